@@ -8,8 +8,56 @@ use std::marker::Sized;
 
 use dirs::home_dir;
 use std::env;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
 use toml::Value;
 
+/// The path of the starship configuration file
+///
+/// `$STARSHIP_CONFIG` takes precedence, followed by `starship.toml` inside
+/// `$XDG_CONFIG_HOME`, and finally `~/.config/starship.toml`.
+pub fn get_config_path() -> Option<PathBuf> {
+    resolve_config_path(
+        env::var_os("STARSHIP_CONFIG"),
+        env::var_os("XDG_CONFIG_HOME"),
+        home_dir(),
+    )
+}
+
+/// The path of the configuration file that `starship config` edits
+///
+/// If `$STARSHIP_CONFIG` lists several files, this is the last one, i.e. the
+/// overlay that takes precedence over the others.
+pub fn get_editable_config_path() -> Option<PathBuf> {
+    last_config_path(get_config_path()?.as_os_str())
+}
+
+fn last_config_path(paths: &OsStr) -> Option<PathBuf> {
+    env::split_paths(paths)
+        .filter(|path| !path.as_os_str().is_empty())
+        .last()
+}
+
+fn resolve_config_path(
+    starship_config: Option<OsString>,
+    xdg_config_home: Option<OsString>,
+    home: Option<PathBuf>,
+) -> Option<PathBuf> {
+    if let Some(path) = starship_config.filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+
+    // The XDG spec requires relative paths to be ignored
+    let xdg_config_home = xdg_config_home
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute());
+    if let Some(config_home) = xdg_config_home {
+        return Some(config_home.join("starship.toml"));
+    }
+
+    Some(home?.join(".config/starship.toml"))
+}
+
 /// Root config of a module.
 pub trait RootModuleConfig<'a>
 where
@@ -163,7 +211,7 @@ impl StarshipConfig {
     pub fn initialize() -> Self {
         if let Some(file_data) = Self::config_from_file() {
             StarshipConfig {
-                config: Some(file_data),
+                config: Some(apply_theme(file_data)),
             }
         } else {
             StarshipConfig {
@@ -174,33 +222,43 @@ impl StarshipConfig {
 
     /// Create a config from a starship configuration file
     fn config_from_file() -> Option<Value> {
-        let file_path = if let Ok(path) = env::var("STARSHIP_CONFIG") {
-            // Use $STARSHIP_CONFIG as the config path if available
-            log::debug!("STARSHIP_CONFIG is set: \n{}", &path);
-            path
-        } else {
-            // Default to using ~/.config/starship.toml
-            log::debug!("STARSHIP_CONFIG is not set");
-            let config_path = home_dir()?.join(".config/starship.toml");
-            let config_path_str = config_path.to_str()?.to_owned();
-            log::debug!("Using default config path: {}", config_path_str);
-            config_path_str
-        };
-
-        let toml_content = match utils::read_file(&file_path) {
-            Ok(content) => {
-                log::trace!("Config file content: \n{}", &content);
-                Some(content)
-            }
-            Err(e) => {
-                log::debug!("Unable to read config file content: \n{}", &e);
-                None
-            }
-        }?;
-
-        let config = toml::from_str(&toml_content).ok()?;
-        log::debug!("Config parsed: \n{:?}", &config);
-        Some(config)
+        let file_path = get_config_path()?;
+        log::debug!("Using config path: {:?}", &file_path);
+
+        Self::config_from_paths(file_path.as_ref())
+    }
+
+    /// Create a config from a list of configuration files, separated like `$PATH`.
+    /// Each file is merged over the ones before it, table by table, so a later
+    /// file can override a single option of a module.
+    ///
+    /// Unlike `initialize`, no theme is applied, so the result can be written back
+    /// to disk.
+    pub fn config_from_paths(paths: &OsStr) -> Option<Value> {
+        env::split_paths(paths)
+            .filter_map(|path| {
+                let toml_content = match utils::read_file(&path) {
+                    Ok(content) => {
+                        log::trace!("Config file content: \n{}", &content);
+                        Some(content)
+                    }
+                    Err(e) => {
+                        log::debug!("Unable to read config file content: \n{}", &e);
+                        None
+                    }
+                }?;
+
+                let config = toml::from_str(&toml_content).ok()?;
+                log::debug!("Config parsed: \n{:?}", &config);
+                Some(config)
+            })
+            .fold(None, |merged, config| match merged {
+                Some(mut merged) => {
+                    merge_config(&mut merged, config);
+                    Some(merged)
+                }
+                None => Some(config),
+            })
     }
 
     /// Get the subset of the table for a module by its name
@@ -247,6 +305,62 @@ impl StarshipConfig {
     }
 }
 
+/// Return the config overlay of a bundled theme, or `None` if no such theme exists.
+///
+/// The `full` theme is the default prompt, so it has an empty overlay.
+fn builtin_theme(name: &str) -> Option<&'static str> {
+    match name {
+        "full" => Some(""),
+        "minimal" => Some(include_str!("themes/minimal.toml")),
+        _ => None,
+    }
+}
+
+/// Merge the bundled theme selected by the root `theme` option underneath the
+/// user's config, so that any option set by the user takes precedence.
+fn apply_theme(config: Value) -> Value {
+    let theme_name = match config.get("theme").and_then(Value::as_str) {
+        Some(name) => name,
+        None => return config,
+    };
+
+    let overlay = match builtin_theme(theme_name) {
+        Some(overlay) => overlay,
+        None => {
+            log::debug!("Unknown theme \"{}\", ignoring it", theme_name);
+            return config;
+        }
+    };
+
+    match toml::from_str::<Value>(overlay) {
+        Ok(mut theme) => {
+            merge_config(&mut theme, config);
+            theme
+        }
+        Err(e) => {
+            log::debug!("Unable to parse theme \"{}\": \n{}", theme_name, &e);
+            config
+        }
+    }
+}
+
+/// Recursively merge `overlay` into `base`, with values from `overlay` winning.
+fn merge_config(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Table(base_table), Value::Table(overlay_table)) => {
+            for (key, value) in overlay_table {
+                match base_table.get_mut(&key) {
+                    Some(base_value) => merge_config(base_value, value),
+                    None => {
+                        base_table.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
 #[derive(Clone)]
 pub struct SegmentConfig<'a> {
     pub value: &'a str,
@@ -316,6 +430,36 @@ impl Default for SegmentConfig<'static> {
     }
 }
 
+/// The set of symbols modules should render, selected by the root `symbol_style` option.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymbolStyle {
+    Nerd,
+    Unicode,
+    Ascii,
+}
+
+impl SymbolStyle {
+    /// Pick the symbol matching this style out of a module's alternate symbols.
+    pub fn select<'b, T>(self, nerd: &'b T, unicode: &'b T, ascii: &'b T) -> &'b T {
+        match self {
+            SymbolStyle::Nerd => nerd,
+            SymbolStyle::Unicode => unicode,
+            SymbolStyle::Ascii => ascii,
+        }
+    }
+}
+
+impl<'a> ModuleConfig<'a> for SymbolStyle {
+    fn from_config(config: &Value) -> Option<Self> {
+        match config.as_str()?.to_lowercase().as_str() {
+            "nerd" => Some(SymbolStyle::Nerd),
+            "unicode" => Some(SymbolStyle::Unicode),
+            "ascii" => Some(SymbolStyle::Ascii),
+            _ => None,
+        }
+    }
+}
+
 /** Parse a style string which represents an ansi style. Valid tokens in the style
  string include the following:
  - 'fg:<color>'    (specifies that the color read should be a foreground color)
@@ -424,6 +568,74 @@ mod tests {
     use super::*;
     use starship_module_config_derive::ModuleConfig;
 
+    #[test]
+    fn test_config_from_paths() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let base = dir.path().join("base.toml");
+        let overlay = dir.path().join("overlay.toml");
+        std::fs::write(
+            &base,
+            "add_newline = false\n[rust]\nsymbol = \"R \"\nstyle = \"red\"\n",
+        )?;
+        std::fs::write(&overlay, "[rust]\nsymbol = \"🦀 \"\n")?;
+
+        let missing = dir.path().join("missing.toml");
+        let paths = env::join_paths(&[&base, &missing, &overlay]).unwrap();
+        let config = StarshipConfig::config_from_paths(&paths).unwrap();
+
+        assert_eq!(config["add_newline"], Value::Boolean(false));
+        assert_eq!(config["rust"]["symbol"], Value::from("🦀 "));
+        assert_eq!(config["rust"]["style"], Value::from("red"));
+
+        let single = StarshipConfig::config_from_paths(base.as_os_str()).unwrap();
+        assert_eq!(single["rust"]["symbol"], Value::from("R "));
+
+        assert_eq!(StarshipConfig::config_from_paths(missing.as_os_str()), None);
+        dir.close()
+    }
+
+    #[test]
+    fn test_last_config_path() {
+        let paths =
+            env::join_paths(&["/etc/starship/team.toml", "/home/astronaut/starship.toml"]).unwrap();
+        assert_eq!(
+            last_config_path(&paths),
+            Some(PathBuf::from("/home/astronaut/starship.toml"))
+        );
+        assert_eq!(
+            last_config_path(OsStr::new("/home/astronaut/starship.toml")),
+            Some(PathBuf::from("/home/astronaut/starship.toml"))
+        );
+        assert_eq!(last_config_path(OsStr::new("")), None);
+    }
+
+    #[test]
+    fn test_resolve_config_path() {
+        let home = Some(PathBuf::from("/home/astronaut"));
+
+        assert_eq!(
+            resolve_config_path(None, None, home.clone()),
+            Some(PathBuf::from("/home/astronaut/.config/starship.toml"))
+        );
+        assert_eq!(
+            resolve_config_path(None, Some("/xdg/config".into()), home.clone()),
+            Some(PathBuf::from("/xdg/config/starship.toml"))
+        );
+        assert_eq!(
+            resolve_config_path(
+                Some("/custom/starship.toml".into()),
+                Some("/xdg/config".into()),
+                home.clone()
+            ),
+            Some(PathBuf::from("/custom/starship.toml"))
+        );
+        assert_eq!(
+            resolve_config_path(Some("".into()), Some("relative/config".into()), home),
+            Some(PathBuf::from("/home/astronaut/.config/starship.toml"))
+        );
+        assert_eq!(resolve_config_path(None, None, None), None);
+    }
+
     #[test]
     fn test_load_config() {
         #[derive(Clone, ModuleConfig)]
@@ -559,6 +771,54 @@ mod tests {
         assert_eq!(rust_config.switch_c, Switch::OFF);
     }
 
+    #[test]
+    fn test_apply_minimal_theme() {
+        let config = toml::toml! {
+            theme = "minimal"
+
+            [directory]
+            style = "red"
+        };
+        let themed = apply_theme(config);
+
+        assert_eq!(themed["add_newline"], Value::Boolean(false));
+        assert_eq!(
+            themed["prompt_order"],
+            Value::Array(vec![Value::from("directory"), Value::from("character")])
+        );
+        assert_eq!(themed["directory"]["truncation_length"], Value::Integer(1));
+        assert_eq!(themed["directory"]["style"], Value::from("red"));
+    }
+
+    #[test]
+    fn test_apply_theme_user_config_wins() {
+        let config = toml::toml! {
+            theme = "minimal"
+            add_newline = true
+        };
+        let themed = apply_theme(config);
+
+        assert_eq!(themed["add_newline"], Value::Boolean(true));
+    }
+
+    #[test]
+    fn test_apply_full_theme() {
+        let config = toml::toml! {
+            theme = "full"
+        };
+
+        assert_eq!(apply_theme(config.clone()), config);
+    }
+
+    #[test]
+    fn test_apply_unknown_theme() {
+        let config = toml::toml! {
+            theme = "some_unknown_theme"
+        };
+
+        assert_eq!(apply_theme(config.clone()), config);
+    }
+
     #[test]
     fn test_from_string() {
         let config = Value::String(String::from("S"));
@@ -655,4 +915,30 @@ mod tests {
             Style::new().fg(Color::Fixed(125)).on(Color::Fixed(127))
         );
     }
+
+    #[test]
+    fn test_symbol_style() {
+        assert_eq!(
+            <SymbolStyle>::from_config(&Value::from("ascii")),
+            Some(SymbolStyle::Ascii)
+        );
+        assert_eq!(
+            <SymbolStyle>::from_config(&Value::from("Unicode")),
+            Some(SymbolStyle::Unicode)
+        );
+        assert_eq!(<SymbolStyle>::from_config(&Value::from("emoji")), None);
+
+        let nerd = SegmentConfig::new("N");
+        let unicode = SegmentConfig::new("U");
+        let ascii = SegmentConfig::new("A");
+        assert_eq!(SymbolStyle::Nerd.select(&nerd, &unicode, &ascii).value, "N");
+        assert_eq!(
+            SymbolStyle::Unicode.select(&nerd, &unicode, &ascii).value,
+            "U"
+        );
+        assert_eq!(
+            SymbolStyle::Ascii.select(&nerd, &unicode, &ascii).value,
+            "A"
+        );
+    }
 }
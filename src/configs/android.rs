@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct AndroidConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for AndroidConfig<'a> {
+    fn new() -> Self {
+        AndroidConfig {
+            symbol: SegmentConfig::new("🤖 "),
+            unicode_symbol: SegmentConfig::new("🤖 "),
+            ascii_symbol: SegmentConfig::new("android "),
+            style: Color::Green.bold(),
+            disabled: false,
+        }
+    }
+}
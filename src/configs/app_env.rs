@@ -0,0 +1,35 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct AppEnvConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub variables: Vec<&'a str>,
+    pub production_values: Vec<&'a str>,
+    pub production_style: Style,
+    pub development_values: Vec<&'a str>,
+    pub development_style: Style,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for AppEnvConfig<'a> {
+    fn new() -> Self {
+        AppEnvConfig {
+            symbol: SegmentConfig::new("⚑ "),
+            unicode_symbol: SegmentConfig::new("⚑ "),
+            ascii_symbol: SegmentConfig::new("env "),
+            variables: vec!["APP_ENV", "RAILS_ENV", "NODE_ENV", "ENVIRONMENT"],
+            production_values: vec!["production", "prod"],
+            production_style: Color::Red.bold(),
+            development_values: vec!["development", "dev"],
+            development_style: Color::Green.bold(),
+            style: Color::Yellow.bold(),
+            disabled: true,
+        }
+    }
+}
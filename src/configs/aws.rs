@@ -14,24 +14,30 @@ pub enum AwsItems {
 #[derive(Clone, ModuleConfig)]
 pub struct AwsConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub profile: SegmentConfig<'a>,
     pub region: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
     pub displayed_items: AwsItems,
     pub region_aliases: HashMap<String, &'a str>,
+    pub short_region: bool,
 }
 
 impl<'a> RootModuleConfig<'a> for AwsConfig<'a> {
     fn new() -> Self {
         AwsConfig {
             symbol: SegmentConfig::new("☁️  "),
+            unicode_symbol: SegmentConfig::new("☁️  "),
+            ascii_symbol: SegmentConfig::new("aws "),
             profile: SegmentConfig::default(),
             region: SegmentConfig::default(),
             style: Color::Yellow.bold(),
             disabled: false,
             displayed_items: AwsItems::All,
             region_aliases: HashMap::new(),
+            short_region: false,
         }
     }
 }
@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct BufConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub workspace_symbol: SegmentConfig<'a>,
+    pub show_module_name: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for BufConfig<'a> {
+    fn new() -> Self {
+        BufConfig {
+            symbol: SegmentConfig::new("🐃 "),
+            unicode_symbol: SegmentConfig::new("🐃 "),
+            ascii_symbol: SegmentConfig::new("buf "),
+            workspace_symbol: SegmentConfig::new("⊞ "),
+            show_module_name: true,
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
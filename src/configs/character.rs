@@ -8,6 +8,8 @@ pub struct CharacterConfig<'a> {
     pub symbol: SegmentConfig<'a>,
     pub error_symbol: SegmentConfig<'a>,
     pub vicmd_symbol: SegmentConfig<'a>,
+    pub visual_symbol: SegmentConfig<'a>,
+    pub replace_symbol: SegmentConfig<'a>,
     pub use_symbol_for_status: bool,
     pub style_success: Style,
     pub style_failure: Style,
@@ -20,6 +22,8 @@ impl<'a> RootModuleConfig<'a> for CharacterConfig<'a> {
             symbol: SegmentConfig::new("❯"),
             error_symbol: SegmentConfig::new("✖"),
             vicmd_symbol: SegmentConfig::new("❮"),
+            visual_symbol: SegmentConfig::new("❮"),
+            replace_symbol: SegmentConfig::new("❮"),
             use_symbol_for_status: false,
             style_success: Color::Green.bold(),
             style_failure: Color::Red.bold(),
@@ -9,6 +9,9 @@ pub struct CmdDurationConfig<'a> {
     pub prefix: &'a str,
     pub style: Style,
     pub show_milliseconds: bool,
+    pub zero_pad: bool,
+    pub show_completed_at: bool,
+    pub completed_format: &'a str,
     pub disabled: bool,
 }
 
@@ -18,6 +21,9 @@ impl<'a> RootModuleConfig<'a> for CmdDurationConfig<'a> {
             min_time: 2_000,
             prefix: "took ",
             show_milliseconds: false,
+            zero_pad: false,
+            show_completed_at: false,
+            completed_format: "%T",
             style: Color::Yellow.bold(),
             disabled: false,
         }
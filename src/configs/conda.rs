@@ -7,6 +7,9 @@ use starship_module_config_derive::ModuleConfig;
 pub struct CondaConfig<'a> {
     pub truncation_length: usize,
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub mamba_symbol: SegmentConfig<'a>,
     pub environment: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
@@ -20,6 +23,9 @@ impl<'a> RootModuleConfig<'a> for CondaConfig<'a> {
                 value: "C ",
                 style: None,
             },
+            unicode_symbol: SegmentConfig::new("C "),
+            ascii_symbol: SegmentConfig::new("C "),
+            mamba_symbol: SegmentConfig::new("M "),
             environment: SegmentConfig {
                 value: "",
                 style: None,
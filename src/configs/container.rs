@@ -0,0 +1,21 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct ContainerConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ContainerConfig<'a> {
+    fn new() -> Self {
+        ContainerConfig {
+            symbol: SegmentConfig::new("⬢ "),
+            style: Color::Red.bold().dimmed(),
+            disabled: true,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct CrystalConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
 }
@@ -14,6 +16,8 @@ impl<'a> RootModuleConfig<'a> for CrystalConfig<'a> {
     fn new() -> Self {
         CrystalConfig {
             symbol: SegmentConfig::new("🔮 "),
+            unicode_symbol: SegmentConfig::new("🔮 "),
+            ascii_symbol: SegmentConfig::new("cr "),
             style: Color::Red.bold(),
             disabled: false,
         }
@@ -18,6 +18,7 @@ pub struct CustomConfig<'a> {
     pub command: &'a str,
     pub when: Option<&'a str>,
     pub shell: Option<&'a str>,
+    pub pass_context: bool,
     pub description: &'a str,
     pub style: Option<Style>,
     pub disabled: bool,
@@ -35,6 +36,7 @@ impl<'a> RootModuleConfig<'a> for CustomConfig<'a> {
             command: "",
             when: None,
             shell: None,
+            pass_context: false,
             description: "<custom config>",
             style: None,
             disabled: false,
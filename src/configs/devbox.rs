@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct DevboxConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub show_packages: bool,
+    pub style: Style,
+    pub shell_style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for DevboxConfig<'a> {
+    fn new() -> Self {
+        DevboxConfig {
+            symbol: SegmentConfig::new("📦 "),
+            unicode_symbol: SegmentConfig::new("📦 "),
+            ascii_symbol: SegmentConfig::new("devbox "),
+            show_packages: true,
+            style: Color::Purple.bold(),
+            shell_style: Color::Green.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -9,6 +9,11 @@ pub struct DirectoryConfig<'a> {
     pub truncate_to_repo: bool,
     pub fish_style_pwd_dir_length: i64,
     pub use_logical_path: bool,
+    pub collapse_repeated: bool,
+    pub collapse_repeated_symbol: &'a str,
+    pub home_symbol: &'a str,
+    pub system_symbol: &'a str,
+    pub system_style: Option<Style>,
     pub prefix: &'a str,
     pub style: Style,
     pub disabled: bool,
@@ -21,6 +26,11 @@ impl<'a> RootModuleConfig<'a> for DirectoryConfig<'a> {
             truncate_to_repo: true,
             fish_style_pwd_dir_length: 0,
             use_logical_path: true,
+            collapse_repeated: false,
+            collapse_repeated_symbol: "×",
+            home_symbol: "",
+            system_symbol: "",
+            system_style: None,
             prefix: "in ",
             style: Color::Cyan.bold(),
             disabled: false,
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct DockerContextConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub context: SegmentConfig<'a>,
     pub style: Style,
     pub only_with_files: bool,
@@ -16,6 +18,8 @@ impl<'a> RootModuleConfig<'a> for DockerContextConfig<'a> {
     fn new() -> Self {
         DockerContextConfig {
             symbol: SegmentConfig::new("🐳 "),
+            unicode_symbol: SegmentConfig::new("🐳 "),
+            ascii_symbol: SegmentConfig::new("docker "),
             context: SegmentConfig::default(),
             style: Color::Blue.bold(),
             only_with_files: true,
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct DotnetConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub heuristic: bool,
@@ -16,6 +18,8 @@ impl<'a> RootModuleConfig<'a> for DotnetConfig<'a> {
     fn new() -> Self {
         DotnetConfig {
             symbol: SegmentConfig::new("•NET "),
+            unicode_symbol: SegmentConfig::new("•NET "),
+            ascii_symbol: SegmentConfig::new(".NET "),
             version: SegmentConfig::default(),
             style: Color::Blue.bold(),
             heuristic: true,
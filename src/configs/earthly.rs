@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct EarthlyConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for EarthlyConfig<'a> {
+    fn new() -> Self {
+        EarthlyConfig {
+            symbol: SegmentConfig::new("🌍 "),
+            unicode_symbol: SegmentConfig::new("🌍 "),
+            ascii_symbol: SegmentConfig::new("earthly "),
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
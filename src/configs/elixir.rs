@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct ElixirConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub otp_version: SegmentConfig<'a>,
     pub style: Style,
@@ -16,6 +18,8 @@ impl<'a> RootModuleConfig<'a> for ElixirConfig<'a> {
     fn new() -> Self {
         ElixirConfig {
             symbol: SegmentConfig::new("💧 "),
+            unicode_symbol: SegmentConfig::new("💧 "),
+            ascii_symbol: SegmentConfig::new("ex "),
             version: SegmentConfig::default(),
             otp_version: SegmentConfig::default(),
             style: Color::Purple.bold(),
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct ElmConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for ElmConfig<'a> {
     fn new() -> Self {
         ElmConfig {
             symbol: SegmentConfig::new("🌳 "),
+            unicode_symbol: SegmentConfig::new("🌳 "),
+            ascii_symbol: SegmentConfig::new("elm "),
             version: SegmentConfig::default(),
             style: Color::Cyan.bold(),
             disabled: false,
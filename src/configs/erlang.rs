@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct ErlangConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for ErlangConfig<'a> {
     fn new() -> Self {
         ErlangConfig {
             symbol: SegmentConfig::new("🖧 "),
+            unicode_symbol: SegmentConfig::new("🖧 "),
+            ascii_symbol: SegmentConfig::new("erl "),
             version: SegmentConfig::default(),
             style: Color::Red.bold(),
             disabled: false,
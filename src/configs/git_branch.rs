@@ -2,13 +2,18 @@ use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
 
 use ansi_term::{Color, Style};
 use starship_module_config_derive::ModuleConfig;
+use std::collections::HashMap;
 
 #[derive(Clone, ModuleConfig)]
 pub struct GitBranchConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub branch_type_symbols: HashMap<String, &'a str>,
     pub truncation_length: i64,
     pub truncation_symbol: &'a str,
     pub branch_name: SegmentConfig<'a>,
+    pub dirty: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
 }
@@ -17,9 +22,13 @@ impl<'a> RootModuleConfig<'a> for GitBranchConfig<'a> {
     fn new() -> Self {
         GitBranchConfig {
             symbol: SegmentConfig::new(" "),
+            unicode_symbol: SegmentConfig::new("⎇ "),
+            ascii_symbol: SegmentConfig::new("git:"),
+            branch_type_symbols: HashMap::new(),
             truncation_length: std::i64::MAX,
             truncation_symbol: "…",
             branch_name: SegmentConfig::default(),
+            dirty: SegmentConfig::default(),
             style: Color::Purple.bold(),
             disabled: false,
         }
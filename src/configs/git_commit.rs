@@ -6,11 +6,14 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct GitCommitConfig<'a> {
     pub commit_hash_length: usize,
+    pub hash_truncation: &'a str,
     pub hash: SegmentConfig<'a>,
     pub prefix: &'a str,
     pub suffix: &'a str,
     pub style: Style,
     pub only_detached: bool,
+    pub tag_symbol: &'a str,
+    pub tag_disabled: bool,
     pub disabled: bool,
 }
 
@@ -19,11 +22,14 @@ impl<'a> RootModuleConfig<'a> for GitCommitConfig<'a> {
         GitCommitConfig {
             // be consistent with git by default, which has DEFAULT_ABBREV set to 7
             commit_hash_length: 7,
+            hash_truncation: "prefix",
             hash: SegmentConfig::default(),
             prefix: "(",
             suffix: ") ",
             style: Color::Green.bold(),
             only_detached: true,
+            tag_symbol: " 🏷 ",
+            tag_disabled: true,
             disabled: false,
         }
     }
@@ -0,0 +1,32 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GitFetchConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub min_age: u64,
+    pub stale_age: u64,
+    pub prefix: &'a str,
+    pub suffix: &'a str,
+    pub style: Style,
+    pub stale_style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GitFetchConfig<'a> {
+    fn new() -> Self {
+        GitFetchConfig {
+            symbol: SegmentConfig::new("fetched "),
+            min_age: 0,
+            // one day
+            stale_age: 86_400,
+            prefix: "",
+            suffix: " ago ",
+            style: Color::Green.bold(),
+            stale_style: Color::Red.bold(),
+            disabled: true,
+        }
+    }
+}
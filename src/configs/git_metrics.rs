@@ -0,0 +1,31 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::Color;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GitMetricsConfig<'a> {
+    pub added: SegmentConfig<'a>,
+    pub deleted: SegmentConfig<'a>,
+    pub separator: SegmentConfig<'a>,
+    pub only_nonzero_diffs: bool,
+    pub use_git_executable: bool,
+    pub prefix: &'a str,
+    pub suffix: &'a str,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GitMetricsConfig<'a> {
+    fn new() -> Self {
+        GitMetricsConfig {
+            added: SegmentConfig::new("+").with_style(Some(Color::Green.bold())),
+            deleted: SegmentConfig::new("-").with_style(Some(Color::Red.bold())),
+            separator: SegmentConfig::new(" "),
+            only_nonzero_diffs: true,
+            use_git_executable: false,
+            prefix: "",
+            suffix: " ",
+            disabled: true,
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub struct GitStateConfig<'a> {
     pub am: SegmentConfig<'a>,
     pub am_or_rebase: SegmentConfig<'a>,
     pub progress_divider: SegmentConfig<'a>,
+    pub show_merge_source: bool,
     pub style: Style,
     pub disabled: bool,
 }
@@ -28,6 +29,7 @@ impl<'a> RootModuleConfig<'a> for GitStateConfig<'a> {
             am: SegmentConfig::new("AM"),
             am_or_rebase: SegmentConfig::new("AM/REBASE"),
             progress_divider: SegmentConfig::new("/"),
+            show_merge_source: true,
             style: Color::Yellow.bold(),
             disabled: false,
         }
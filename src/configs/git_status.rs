@@ -7,10 +7,16 @@ use starship_module_config_derive::ModuleConfig;
 pub struct GitStatusConfig<'a> {
     pub stashed: SegmentConfig<'a>,
     pub stashed_count: CountConfig,
+    pub stash_message: SegmentConfig<'a>,
+    pub show_stash_message: bool,
+    pub stash_age: SegmentConfig<'a>,
+    pub show_stash_age: bool,
     pub ahead: SegmentConfig<'a>,
     pub behind: SegmentConfig<'a>,
     pub diverged: SegmentConfig<'a>,
     pub show_sync_count: bool,
+    pub compare_remote: &'a str,
+    pub upstream_gone: SegmentConfig<'a>,
     pub conflicted: SegmentConfig<'a>,
     pub conflicted_count: CountConfig,
     pub deleted: SegmentConfig<'a>,
@@ -23,6 +29,12 @@ pub struct GitStatusConfig<'a> {
     pub staged_count: CountConfig,
     pub untracked: SegmentConfig<'a>,
     pub untracked_count: CountConfig,
+    pub max_changed_files: usize,
+    pub todo: SegmentConfig<'a>,
+    pub show_todo_count: bool,
+    pub todo_markers: Vec<&'a str>,
+    pub todo_max_files: usize,
+    pub todo_max_file_size: u64,
     pub prefix: &'a str,
     pub suffix: &'a str,
     pub style: Style,
@@ -34,11 +46,17 @@ impl<'a> RootModuleConfig<'a> for GitStatusConfig<'a> {
         GitStatusConfig {
             stashed: SegmentConfig::new("$"),
             stashed_count: CountConfig::default(),
+            stash_message: SegmentConfig::new(" "),
+            show_stash_message: false,
+            stash_age: SegmentConfig::new(" "),
+            show_stash_age: false,
             ahead: SegmentConfig::new("⇡"),
             behind: SegmentConfig::new("⇣"),
             diverged: SegmentConfig::new("⇕"),
             conflicted: SegmentConfig::new("="),
             show_sync_count: false,
+            compare_remote: "",
+            upstream_gone: SegmentConfig::new("⊘"),
             conflicted_count: CountConfig::default(),
             deleted: SegmentConfig::new("✘"),
             deleted_count: CountConfig::default(),
@@ -50,6 +68,12 @@ impl<'a> RootModuleConfig<'a> for GitStatusConfig<'a> {
             staged_count: CountConfig::default(),
             untracked: SegmentConfig::new("?"),
             untracked_count: CountConfig::default(),
+            max_changed_files: 0,
+            todo: SegmentConfig::new("✎"),
+            show_todo_count: false,
+            todo_markers: vec!["TODO", "FIXME"],
+            todo_max_files: 100,
+            todo_max_file_size: 1_048_576,
             prefix: "[",
             suffix: "] ",
             style: Color::Red.bold(),
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct HaskellConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for HaskellConfig<'a> {
     fn new() -> Self {
         HaskellConfig {
             symbol: SegmentConfig::new("λ "),
+            unicode_symbol: SegmentConfig::new("λ "),
+            ascii_symbol: SegmentConfig::new("hs "),
             version: SegmentConfig::default(),
             style: Color::Red.bold(),
             disabled: false,
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct HelmfileConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub show_environment: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for HelmfileConfig<'a> {
+    fn new() -> Self {
+        HelmfileConfig {
+            symbol: SegmentConfig::new("⎈ "),
+            unicode_symbol: SegmentConfig::new("⎈ "),
+            ascii_symbol: SegmentConfig::new("helmfile "),
+            show_environment: true,
+            style: Color::White.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct HgBranchConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub truncation_length: i64,
     pub truncation_symbol: &'a str,
     pub branch_name: SegmentConfig<'a>,
@@ -17,6 +19,8 @@ impl<'a> RootModuleConfig<'a> for HgBranchConfig<'a> {
     fn new() -> Self {
         HgBranchConfig {
             symbol: SegmentConfig::new(" "),
+            unicode_symbol: SegmentConfig::new("☿ "),
+            ascii_symbol: SegmentConfig::new("hg:"),
             truncation_length: std::i64::MAX,
             truncation_symbol: "…",
             branch_name: SegmentConfig::default(),
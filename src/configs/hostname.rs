@@ -9,6 +9,7 @@ pub struct HostnameConfig<'a> {
     pub prefix: &'a str,
     pub suffix: &'a str,
     pub trim_at: &'a str,
+    pub baseline: &'a str,
     pub style: Style,
     pub disabled: bool,
 }
@@ -20,6 +21,7 @@ impl<'a> RootModuleConfig<'a> for HostnameConfig<'a> {
             prefix: "",
             suffix: "",
             trim_at: ".",
+            baseline: "",
             style: Color::Green.bold().dimmed(),
             disabled: false,
         }
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct JobsConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub threshold: i64,
     pub style: Style,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for JobsConfig<'a> {
     fn new() -> Self {
         JobsConfig {
             symbol: SegmentConfig::new("✦"),
+            unicode_symbol: SegmentConfig::new("✦"),
+            ascii_symbol: SegmentConfig::new("*"),
             threshold: 1,
             style: Color::Blue.bold(),
             disabled: false,
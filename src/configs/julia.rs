@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct JuliaConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for JuliaConfig<'a> {
     fn new() -> Self {
         JuliaConfig {
             symbol: SegmentConfig::new("ஃ "),
+            unicode_symbol: SegmentConfig::new("ஃ "),
+            ascii_symbol: SegmentConfig::new("jl "),
             version: SegmentConfig::default(),
             style: Color::Purple.bold(),
             disabled: false,
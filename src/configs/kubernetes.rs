@@ -7,8 +7,11 @@ use std::collections::HashMap;
 #[derive(Clone, ModuleConfig)]
 pub struct KubernetesConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub context: SegmentConfig<'a>,
     pub namespace: SegmentConfig<'a>,
+    pub namespace_file: &'a str,
     pub style: Style,
     pub disabled: bool,
     pub context_aliases: HashMap<String, &'a str>,
@@ -18,8 +21,11 @@ impl<'a> RootModuleConfig<'a> for KubernetesConfig<'a> {
     fn new() -> Self {
         KubernetesConfig {
             symbol: SegmentConfig::new("☸ "),
+            unicode_symbol: SegmentConfig::new("☸ "),
+            ascii_symbol: SegmentConfig::new("k8s "),
             context: SegmentConfig::default(),
             namespace: SegmentConfig::default(),
+            namespace_file: "",
             style: Color::Cyan.bold(),
             disabled: true,
             context_aliases: HashMap::new(),
@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct LocaleConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub show_full: bool,
+    pub default_locale: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for LocaleConfig<'a> {
+    fn new() -> Self {
+        LocaleConfig {
+            symbol: SegmentConfig::new("🌐 "),
+            unicode_symbol: SegmentConfig::new("🌐 "),
+            ascii_symbol: SegmentConfig::new("lang "),
+            show_full: false,
+            default_locale: "C",
+            style: Color::White.dimmed(),
+            disabled: true,
+        }
+    }
+}
@@ -9,6 +9,8 @@ pub struct MemoryConfig<'a> {
     pub show_swap: bool,
     pub threshold: i64,
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub separator: SegmentConfig<'a>,
     pub ram: SegmentConfig<'a>,
     pub swap: SegmentConfig<'a>,
@@ -23,6 +25,8 @@ impl<'a> RootModuleConfig<'a> for MemoryConfig<'a> {
             show_swap: true,
             threshold: 75,
             symbol: SegmentConfig::new("🐏 "),
+            unicode_symbol: SegmentConfig::new("🐏 "),
+            ascii_symbol: SegmentConfig::new("mem "),
             separator: SegmentConfig::new(" | "),
             ram: SegmentConfig::default(),
             swap: SegmentConfig::default(),
@@ -1,41 +1,57 @@
+pub mod android;
+pub mod app_env;
 pub mod aws;
 pub mod battery;
+pub mod buf;
 pub mod character;
 pub mod cmd_duration;
 pub mod conda;
+pub mod container;
 pub mod crystal;
 pub mod custom;
+pub mod devbox;
 pub mod directory;
 pub mod docker_context;
 pub mod dotnet;
+pub mod earthly;
 pub mod elixir;
 pub mod elm;
 pub mod env_var;
 pub mod erlang;
 pub mod git_branch;
 pub mod git_commit;
+pub mod git_fetch;
+pub mod git_metrics;
 pub mod git_state;
 pub mod git_status;
 pub mod go;
 pub mod haskell;
+pub mod helmfile;
 pub mod hg_branch;
 pub mod hostname;
 pub mod java;
 pub mod jobs;
 pub mod julia;
 pub mod kubernetes;
+pub mod locale;
 pub mod memory_usage;
+pub mod nix_profile;
 pub mod nix_shell;
 pub mod nodejs;
 pub mod package;
 pub mod php;
+pub mod pid;
 pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod singularity;
+pub mod sops;
 mod starship_root;
+pub mod systemd;
+pub mod task_runner;
 pub mod terraform;
 pub mod time;
 pub mod username;
+pub mod zellij;
 
 pub use starship_root::*;
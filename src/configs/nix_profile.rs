@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct NixProfileConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for NixProfileConfig<'a> {
+    fn new() -> Self {
+        NixProfileConfig {
+            symbol: SegmentConfig::new("❄️  gen "),
+            unicode_symbol: SegmentConfig::new("❄️  gen "),
+            ascii_symbol: SegmentConfig::new("nix gen "),
+            style: Color::Blue.bold(),
+            disabled: true,
+        }
+    }
+}
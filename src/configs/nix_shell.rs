@@ -10,6 +10,8 @@ pub struct NixShellConfig<'a> {
     pub pure_msg: SegmentConfig<'a>,
     pub style: Style,
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub disabled: bool,
 }
 
@@ -21,6 +23,8 @@ impl<'a> RootModuleConfig<'a> for NixShellConfig<'a> {
             pure_msg: SegmentConfig::new("pure"),
             style: Color::Blue.bold(),
             symbol: SegmentConfig::new("❄️  "),
+            unicode_symbol: SegmentConfig::new("❄️  "),
+            ascii_symbol: SegmentConfig::new("nix "),
             disabled: false,
         }
     }
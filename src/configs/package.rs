@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct PackageConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub style: Style,
     pub display_private: bool,
     pub disabled: bool,
@@ -15,6 +17,8 @@ impl<'a> RootModuleConfig<'a> for PackageConfig<'a> {
     fn new() -> Self {
         PackageConfig {
             symbol: SegmentConfig::new("📦 "),
+            unicode_symbol: SegmentConfig::new("📦 "),
+            ascii_symbol: SegmentConfig::new("pkg "),
             style: Color::Fixed(208).bold(),
             display_private: false,
             disabled: false,
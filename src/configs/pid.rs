@@ -0,0 +1,21 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct PidConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for PidConfig<'a> {
+    fn new() -> Self {
+        PidConfig {
+            symbol: SegmentConfig::new("PID "),
+            style: Color::Purple.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct RubyConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
 }
@@ -14,6 +16,8 @@ impl<'a> RootModuleConfig<'a> for RubyConfig<'a> {
     fn new() -> Self {
         RubyConfig {
             symbol: SegmentConfig::new("💎 "),
+            unicode_symbol: SegmentConfig::new("💎 "),
+            ascii_symbol: SegmentConfig::new("rb "),
             style: Color::Red.bold(),
             disabled: false,
         }
@@ -6,7 +6,10 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct RustConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
+    pub registry: SegmentConfig<'a>,
     pub style: Style,
     pub disabled: bool,
 }
@@ -15,7 +18,10 @@ impl<'a> RootModuleConfig<'a> for RustConfig<'a> {
     fn new() -> Self {
         RustConfig {
             symbol: SegmentConfig::new("🦀 "),
+            unicode_symbol: SegmentConfig::new("🦀 "),
+            ascii_symbol: SegmentConfig::new("rs "),
             version: SegmentConfig::default(),
+            registry: SegmentConfig::default(),
             style: Color::Red.bold(),
             disabled: false,
         }
@@ -0,0 +1,31 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct SopsConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub key_available: SegmentConfig<'a>,
+    pub key_missing: SegmentConfig<'a>,
+    pub style: Style,
+    pub key_missing_style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for SopsConfig<'a> {
+    fn new() -> Self {
+        SopsConfig {
+            symbol: SegmentConfig::new("🔐 "),
+            unicode_symbol: SegmentConfig::new("🔐 "),
+            ascii_symbol: SegmentConfig::new("sops "),
+            key_available: SegmentConfig::new("key"),
+            key_missing: SegmentConfig::new("no key"),
+            style: Color::Green.bold(),
+            key_missing_style: Color::Red.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::config::{ModuleConfig, RootModuleConfig};
+use crate::config::{ModuleConfig, RootModuleConfig, SymbolStyle};
 
 use starship_module_config_derive::ModuleConfig;
 
@@ -7,6 +7,12 @@ pub struct StarshipRootConfig<'a> {
     pub add_newline: bool,
     pub prompt_order: Vec<&'a str>,
     pub scan_timeout: u64,
+    pub scan_depth: usize,
+    pub scan_ignore: Vec<&'a str>,
+    pub symbol_style: SymbolStyle,
+    pub render_threads: usize,
+    pub max_ancestor_scan_depth: usize,
+    pub debug_to_stderr: bool,
 }
 
 impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
@@ -20,17 +26,27 @@ impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
                 "username",
                 "hostname",
                 "singularity",
+                "container",
+                "systemd",
+                "zellij",
                 "kubernetes",
                 "directory",
                 "git_branch",
                 "git_commit",
+                "git_fetch",
                 "git_state",
+                "git_metrics",
                 "git_status",
                 "hg_branch",
                 "docker_context",
                 "package",
+                "task_runner",
+                "earthly",
+                "helmfile",
                 // ↓ Toolchain version modules ↓
                 // (Let's keep these sorted alphabetically)
+                "android",
+                "buf",
                 "dotnet",
                 "elixir",
                 "elm",
@@ -47,21 +63,33 @@ impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
                 "terraform",
                 // ↑ Toolchain version modules ↑
                 "nix_shell",
+                "devbox",
+                "nix_profile",
                 "conda",
                 "memory_usage",
                 "aws",
+                "app_env",
+                "sops",
                 "env_var",
+                "locale",
                 "crystal",
                 "cmd_duration",
                 "custom",
                 "line_break",
                 "jobs",
+                "pid",
                 #[cfg(feature = "battery")]
                 "battery",
                 "time",
                 "character",
             ],
             scan_timeout: 30,
+            scan_depth: 1,
+            scan_ignore: vec![".git", "node_modules"],
+            symbol_style: SymbolStyle::Nerd,
+            render_threads: 0,
+            max_ancestor_scan_depth: 0,
+            debug_to_stderr: false,
         }
     }
 }
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct SystemdConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub show_unit: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for SystemdConfig<'a> {
+    fn new() -> Self {
+        SystemdConfig {
+            symbol: SegmentConfig::new("⚙ "),
+            unicode_symbol: SegmentConfig::new("⚙ "),
+            ascii_symbol: SegmentConfig::new("systemd "),
+            show_unit: true,
+            style: Color::Purple.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct TaskRunnerConfig<'a> {
+    pub task_symbol: SegmentConfig<'a>,
+    pub just_symbol: SegmentConfig<'a>,
+    pub make_symbol: SegmentConfig<'a>,
+    pub detect_order: Vec<&'a str>,
+    pub show_count: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for TaskRunnerConfig<'a> {
+    fn new() -> Self {
+        TaskRunnerConfig {
+            task_symbol: SegmentConfig::new("task"),
+            just_symbol: SegmentConfig::new("just"),
+            make_symbol: SegmentConfig::new("make"),
+            detect_order: vec!["task", "just", "make"],
+            show_count: true,
+            style: Color::Blue.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -6,6 +6,9 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct TerraformConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub terragrunt_symbol: SegmentConfig<'a>,
     pub workspace: SegmentConfig<'a>,
     pub version: SegmentConfig<'a>,
     pub show_version: bool,
@@ -17,6 +20,9 @@ impl<'a> RootModuleConfig<'a> for TerraformConfig<'a> {
     fn new() -> Self {
         TerraformConfig {
             symbol: SegmentConfig::new("💠 "),
+            unicode_symbol: SegmentConfig::new("💠 "),
+            ascii_symbol: SegmentConfig::new("tf "),
+            terragrunt_symbol: SegmentConfig::new("🌱 "),
             workspace: SegmentConfig::default(),
             version: SegmentConfig::default(),
             show_version: false,
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct ZellijConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub unicode_symbol: SegmentConfig<'a>,
+    pub ascii_symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ZellijConfig<'a> {
+    fn new() -> Self {
+        ZellijConfig {
+            symbol: SegmentConfig::new("⧉ "),
+            unicode_symbol: SegmentConfig::new("⧉ "),
+            ascii_symbol: SegmentConfig::new("zellij "),
+            style: Color::Cyan.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::io::ErrorKind;
 use std::process;
 use std::process::Command;
 
-use starship::config::StarshipConfig;
+use crate::config::{self, StarshipConfig};
 use std::fs::File;
 use std::io::Write;
 use toml::map::Map;
@@ -21,10 +21,9 @@ pub fn update_configuration(name: &str, value: &str) {
         process::exit(1);
     }
 
-    let starship_config = StarshipConfig::initialize();
-    let mut config = starship_config
-        .config
-        .expect("Failed to load starship config");
+    // Read the file as written, so that the theme isn't saved into it
+    let mut config =
+        StarshipConfig::config_from_paths(&config_path).unwrap_or_else(|| Value::Table(Map::new()));
 
     if let Some(table) = config.as_table_mut() {
         if !table.contains_key(keys[0]) {
@@ -106,17 +105,12 @@ fn get_editor_internal(visual: Option<OsString>, editor: Option<OsString>) -> Os
     STD_EDITOR.into()
 }
 
+/// The config file to edit. If `$STARSHIP_CONFIG` lists several files, only the
+/// last one is edited and written to.
 fn get_config_path() -> OsString {
-    let config_path = env::var_os("STARSHIP_CONFIG").unwrap_or_else(|| "".into());
-    if config_path.is_empty() {
-        dirs::home_dir()
-            .expect("couldn't find home directory")
-            .join(".config/starship.toml")
-            .as_os_str()
-            .to_owned()
-    } else {
-        config_path
-    }
+    config::get_editable_config_path()
+        .expect("couldn't find home directory")
+        .into_os_string()
 }
 
 #[cfg(test)]
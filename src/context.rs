@@ -1,16 +1,21 @@
-use crate::config::StarshipConfig;
+use crate::config::{StarshipConfig, SymbolStyle};
 use crate::module::Module;
 
 use crate::modules;
+use crate::utils;
 use clap::ArgMatches;
 use git2::{ErrorCode::UnbornBranch, Repository, RepositoryState};
 use once_cell::sync::OnceCell;
+use path_slash::PathExt;
+use regex::Regex;
+use serde::de::DeserializeOwned;
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::string::String;
-use std::time::{Duration, SystemTime};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
 
 /// Context contains data or common methods that may be used by multiple modules.
 /// The data contained within Context will be relevant to this particular rendering
@@ -28,11 +33,35 @@ pub struct Context<'a> {
     /// Properties to provide to modules.
     pub properties: HashMap<&'a str, String>,
 
+    /// Private field to store the approximate size of the current directory's files
+    dir_size: OnceCell<Option<u64>>,
+
     /// Private field to store Git information for modules who need it
     repo: OnceCell<Repo>,
 
+    /// Private field to store Jujutsu information for modules who need it
+    jj_repo: OnceCell<Option<JjRepo>>,
+
+    /// Private field to store the tool versions pinned by asdf or mise
+    tool_versions: OnceCell<HashMap<String, String>>,
+
+    /// Private field to store whether the user is connected through SSH
+    ssh_session: OnceCell<bool>,
+
     /// The shell the user is assumed to be running
     pub shell: Shell,
+
+    /// Width of the terminal, in columns
+    pub width: usize,
+
+    /// Height of the terminal, in rows
+    pub height: usize,
+
+    /// How long the last command took, parsed once from the `cmd_duration` property
+    cmd_duration: Option<u128>,
+
+    /// When this context was created, i.e. when rendering started
+    start_time: Instant,
 }
 
 impl<'a> Context<'a> {
@@ -58,6 +87,7 @@ impl<'a> Context<'a> {
     where
         T: Into<PathBuf>,
     {
+        let start_time = Instant::now();
         let config = StarshipConfig::initialize();
 
         // Unwrap the clap arguments into a simple hashtable
@@ -75,14 +105,78 @@ impl<'a> Context<'a> {
 
         let shell = Context::get_shell();
 
-        Context {
+        let width = parse_dimension(properties.get("terminal_width"), default_width);
+        let height = parse_dimension(properties.get("terminal_height"), default_height);
+        let cmd_duration = parse_cmd_duration(properties.get("cmd_duration"));
+        let inline_config = parse_inline_config(properties.get("config_toml"));
+
+        let mut context = Context {
             config,
             properties,
             current_dir,
             dir_contents: OnceCell::new(),
+            dir_size: OnceCell::new(),
             repo: OnceCell::new(),
+            jj_repo: OnceCell::new(),
+            tool_versions: OnceCell::new(),
+            ssh_session: OnceCell::new(),
             shell,
+            width,
+            height,
+            cmd_duration,
+            start_time,
+        };
+
+        if let Some(inline_config) = inline_config {
+            context.set_config(inline_config);
         }
+        context
+    }
+
+    /// Replace the configuration read from the config file with `config`.
+    pub fn set_config(&mut self, config: toml::value::Table) {
+        self.config = StarshipConfig {
+            config: Some(toml::Value::Table(config)),
+        };
+    }
+
+    /// Create a context for the current directory with an empty configuration and
+    /// the given shell, bypassing the `STARSHIP_SHELL` detection
+    #[cfg(test)]
+    pub fn new_with_shell(shell: Shell) -> Context<'a> {
+        let dir = env::current_dir().expect("Unable to identify current directory");
+        let mut context = Context::new_with_dir(ArgMatches::default(), dir);
+        context.config = StarshipConfig {
+            config: Some(toml::Value::Table(toml::value::Table::new())),
+        };
+        context.shell = shell;
+        context
+    }
+
+    /// How long the last command took to execute, in milliseconds.
+    ///
+    /// Returns `None` if no valid `--cmd-duration` was passed.
+    pub fn cmd_duration_millis(&self) -> Option<u128> {
+        self.cmd_duration
+    }
+
+    /// How long it has been since this context was created, which is roughly how
+    /// long the prompt has taken to render so far.
+    pub fn elapsed(&self) -> Duration {
+        self.start_time.elapsed()
+    }
+
+    /// The number of background jobs passed with `--jobs`.
+    ///
+    /// Missing, negative or invalid values count as no jobs.
+    pub fn jobs(&self) -> i64 {
+        parse_jobs(self.properties.get("jobs"))
+    }
+
+    /// Whether there are strictly more than `threshold` background jobs, so
+    /// `jobs_over(0)` is true as soon as there is a single job.
+    pub fn jobs_over(&self, threshold: i64) -> bool {
+        self.jobs() > threshold
     }
 
     /// Convert a `~` in a path to the home directory
@@ -94,6 +188,72 @@ impl<'a> Context<'a> {
         dir
     }
 
+    /// The process ID of the shell, if it was passed with `--pid`.
+    pub fn shell_pid(&self) -> Option<u32> {
+        self.properties.get("pid")?.trim().parse().ok()
+    }
+
+    /// Retrieve the value of an environment variable, if it is set and valid unicode.
+    pub fn get_env<K: AsRef<str>>(&self, key: K) -> Option<String> {
+        env::var(key.as_ref()).ok()
+    }
+
+    /// Retrieve an environment variable as a boolean.
+    ///
+    /// Common truthy (`true`, `1`, `yes`, `on`) and falsy (`false`, `0`, `no`, `off`)
+    /// values are recognized case-insensitively. `None` is returned if the variable
+    /// is unset or its value is not recognized.
+    pub fn get_env_bool<K: AsRef<str>>(&self, key: K) -> Option<bool> {
+        parse_env_bool(&self.get_env(key)?)
+    }
+
+    /// Whether all styling should be suppressed, as requested by a non-empty
+    /// `NO_COLOR` environment variable (see https://no-color.org) or a dumb terminal.
+    pub fn is_no_color(&self) -> bool {
+        self.detected_color_depth() == ColorDepth::NoColor
+    }
+
+    /// The color depth the terminal supports, as advertised by `$COLORTERM` and
+    /// `$TERM`. A non-empty `$NO_COLOR` means no color at all.
+    pub fn detected_color_depth(&self) -> ColorDepth {
+        detect_color_depth(
+            self.get_env("NO_COLOR"),
+            self.get_env("COLORTERM"),
+            self.get_env("TERM"),
+        )
+    }
+
+    /// Whether the user is connected through SSH, i.e. any of `$SSH_CONNECTION`,
+    /// `$SSH_CLIENT` or `$SSH_TTY` is set and not empty.
+    pub fn is_ssh_session(&self) -> bool {
+        *self.ssh_session.get_or_init(|| {
+            ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+                .iter()
+                .any(|key| self.get_env(key).is_some_and(|value| !value.is_empty()))
+        })
+    }
+
+    /// The label of the active Python virtualenv, preferring the one tools such as
+    /// `venv` put in `$VIRTUAL_ENV_PROMPT` over the name of `$VIRTUAL_ENV` itself.
+    pub fn virtualenv_prompt(&self) -> Option<String> {
+        parse_virtualenv_prompt(
+            self.get_env("VIRTUAL_ENV_PROMPT"),
+            self.get_env("VIRTUAL_ENV"),
+        )
+    }
+
+    /// The kind of container starship is running in, if any.
+    pub fn container_kind(&self) -> Option<ContainerKind> {
+        detect_container(Path::new("/"), self.get_env("container"))
+    }
+
+    /// The editing mode the shell reported through `--keymap`, normalized across
+    /// shells and editors. Assumes insert mode if no keymap was passed.
+    pub fn editor_mode(&self) -> EditorMode {
+        let keymap = self.properties.get("keymap").map(String::as_str);
+        parse_editor_mode(self.shell, keymap.unwrap_or("viins"))
+    }
+
     /// Create a new module
     pub fn new_module(&self, name: &str) -> Module {
         let config = self.config.get_module_config(name);
@@ -102,14 +262,32 @@ impl<'a> Context<'a> {
         Module::new(name, desc, config)
     }
 
+    /// Deserialize the configuration of the module `name` into `T`.
+    ///
+    /// Returns `T::default()` if the module isn't configured, or if its configuration
+    /// fails to deserialize, so a bad config never breaks the whole prompt.
+    pub fn module_config<T: Default + DeserializeOwned>(&self, name: &str) -> T {
+        match self
+            .config
+            .get_module_config(name)
+            .cloned()
+            .map(toml::Value::try_into)
+        {
+            Some(Ok(config)) => config,
+            Some(Err(e)) => {
+                log::warn!("Unable to parse config for \"{}\": {}", name, e);
+                T::default()
+            }
+            None => T::default(),
+        }
+    }
+
     /// Check if `disabled` option of the module is true in configuration file.
     pub fn is_module_disabled_in_config(&self, name: &str) -> bool {
-        let config = self.config.get_module_config(name);
+        let config: toml::value::Table = self.module_config(name);
 
         // If the segment has "disabled" set to "true", don't show it
-        let disabled = config.and_then(|table| table.as_table()?.get("disabled")?.as_bool());
-
-        disabled == Some(true)
+        config.get("disabled").and_then(toml::Value::as_bool) == Some(true)
     }
 
     /// Return whether the specified custom module has a `disabled` option set to true.
@@ -128,7 +306,10 @@ impl<'a> Context<'a> {
             dir_contents: self.dir_contents().ok()?,
             files: &[],
             folders: &[],
+            folders_recursive: &[],
             extensions: &[],
+            extensions_case_insensitive: false,
+            file_globs: &[],
         })
     }
 
@@ -149,74 +330,211 @@ impl<'a> Context<'a> {
                     branch,
                     root,
                     state,
+                    repository: repository.map(Mutex::new),
                 })
             })
     }
 
+    /// Will lazily find the Jujutsu workspace containing the current directory.
+    ///
+    /// Returns `None` outside of a jj workspace.
+    pub fn get_jj_repo(&self) -> Option<&JjRepo> {
+        self.jj_repo
+            .get_or_init(|| {
+                let root = find_jj_root(self.ancestors())?;
+                let (change_id, bookmark) = get_jj_working_copy(&root);
+
+                Some(JjRepo {
+                    root,
+                    change_id,
+                    bookmark,
+                })
+            })
+            .as_ref()
+    }
+
     pub fn dir_contents(&self) -> Result<&DirContents, std::io::Error> {
         self.dir_contents.get_or_try_init(|| {
-            let timeout = Duration::from_millis(self.config.get_root_config().scan_timeout);
-            DirContents::from_path_with_timeout(&self.current_dir, timeout)
+            let root_config = self.config.get_root_config();
+            let timeout = Duration::from_millis(root_config.scan_timeout);
+            DirContents::from_path_with_timeout(
+                &self.current_dir,
+                timeout,
+                root_config.scan_depth,
+                &root_config.scan_ignore,
+            )
+        })
+    }
+
+    /// The approximate size in bytes of the files directly in the current directory.
+    ///
+    /// This needs an extra `stat` per file, so unlike the directory scan itself it is
+    /// only computed the first time a module asks for it.
+    pub fn dir_size_bytes(&self) -> Option<u64> {
+        *self.dir_size.get_or_init(|| {
+            self.dir_contents()
+                .ok()
+                .and_then(|contents| contents.approx_size_bytes())
         })
     }
 
+    /// The version of `tool` pinned in a `.tool-versions` or `mise.toml` file in the
+    /// current directory or one of its parents.
+    ///
+    /// Files closer to the current directory take precedence. If a tool has several
+    /// versions pinned, the first one is returned.
+    pub fn tool_version_pin(&self, tool: &str) -> Option<String> {
+        self.tool_versions
+            .get_or_init(|| find_tool_versions(self.ancestors()))
+            .get(tool)
+            .cloned()
+    }
+
+    /// The current directory followed by its parents, up to the root
+    /// `max_ancestor_scan_depth` directories in total.
+    ///
+    /// Modules searching upwards for a file should use this so that the walk is
+    /// bounded on deep trees or slow mounts. They can still `take` fewer.
+    pub fn ancestors(&self) -> impl Iterator<Item = &Path> {
+        let max_depth = self.config.get_root_config().max_ancestor_scan_depth;
+        limit_ancestors(&self.current_dir, max_depth)
+    }
+
+    /// Replace the home directory at the start of `path` with `~`, returning the
+    /// path with `/` separators.
+    ///
+    /// Paths outside of the home directory are left as is, as are all paths if the
+    /// home directory is the filesystem root.
+    pub fn contract_home(&self, path: &Path) -> String {
+        contract_home_dir(path, dirs::home_dir().as_deref())
+    }
+
+    /// The symbol set modules should render, as chosen by the root `symbol_style` option.
+    pub fn symbol_style(&self) -> SymbolStyle {
+        self.config.get_root_config().symbol_style
+    }
+
+    /// Whether scanning the current directory timed out, so that modules relying on
+    /// its contents may be missing from the prompt.
+    pub fn dir_scan_was_truncated(&self) -> bool {
+        self.dir_contents()
+            .map(|contents| contents.truncated)
+            .unwrap_or(false)
+    }
+
+    /// The shell starship was invoked from, as set in `STARSHIP_SHELL` by the init
+    /// scripts. If it is empty, the name of the parent process is used instead.
     fn get_shell() -> Shell {
         let shell = std::env::var("STARSHIP_SHELL").unwrap_or_default();
-        match shell.as_str() {
-            "bash" => Shell::Bash,
-            "fish" => Shell::Fish,
-            "ion" => Shell::Ion,
-            "powershell" => Shell::PowerShell,
-            "zsh" => Shell::Zsh,
-            _ => Shell::Unknown,
+        if !shell.is_empty() {
+            return parse_shell(&shell);
+        }
+
+        match parent_process_name() {
+            Some(name) => {
+                log::debug!("STARSHIP_SHELL is not set, detected {:?} instead", name);
+                parse_shell(&name)
+            }
+            None => Shell::Unknown,
         }
     }
 }
 
 #[derive(Debug)]
 pub struct DirContents {
+    // The directory that was scanned.
+    base: PathBuf,
     // HashSet of all files, no folders, relative to the base directory given at construction.
     files: HashSet<PathBuf>,
     // HashSet of all file names, e.g. the last section without any folders, as strings.
     file_names: HashSet<String>,
     // HashSet of all folders, relative to the base directory given at construction.
     folders: HashSet<PathBuf>,
+    // HashSet of all folder names, e.g. the last section of each folder, as strings.
+    folder_names: HashSet<String>,
     // HashSet of all extensions found, without dots, e.g. "js" instead of ".js".
     extensions: HashSet<String>,
+    // Whether the scan timed out before every entry could be read.
+    truncated: bool,
 }
 
 impl DirContents {
     #[cfg(test)]
     fn from_path(base: &PathBuf) -> Result<Self, std::io::Error> {
-        Self::from_path_with_timeout(base, Duration::from_secs(30))
+        Self::from_path_with_timeout(base, Duration::from_secs(30), 1, &[])
     }
 
-    fn from_path_with_timeout(base: &PathBuf, timeout: Duration) -> Result<Self, std::io::Error> {
+    /// Scan `base` and up to `depth - 1` levels of its subdirectories, until `timeout`
+    /// is exceeded. All paths are stored relative to `base`.
+    ///
+    /// Folders named in `ignore` are recorded, but never descended into.
+    fn from_path_with_timeout(
+        base: &PathBuf,
+        timeout: Duration,
+        depth: usize,
+        ignore: &[&str],
+    ) -> Result<Self, std::io::Error> {
         let start = SystemTime::now();
 
         let mut folders: HashSet<PathBuf> = HashSet::new();
+        let mut folder_names: HashSet<String> = HashSet::new();
         let mut files: HashSet<PathBuf> = HashSet::new();
         let mut file_names: HashSet<String> = HashSet::new();
         let mut extensions: HashSet<String> = HashSet::new();
 
-        fs::read_dir(base)?
-            .take_while(|_| SystemTime::now().duration_since(start).unwrap() < timeout)
-            .filter_map(Result::ok)
-            .for_each(|entry| {
+        let mut truncated = false;
+
+        let mut pending_dirs = vec![(base.clone(), 1)];
+        while let Some((dir, level)) = pending_dirs.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                // Only the base directory is required to be readable
+                Err(e) if dir == *base => return Err(e),
+                Err(e) => {
+                    log::debug!("Unable to scan directory {:?}: {}", dir, e);
+                    continue;
+                }
+            };
+
+            for entry in entries.filter_map(Result::ok) {
+                if SystemTime::now().duration_since(start).unwrap() >= timeout {
+                    truncated = true;
+                    break;
+                }
+
                 let path = PathBuf::from(entry.path().strip_prefix(base).unwrap());
                 if entry.path().is_dir() {
+                    let folder_name = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string());
+                    let is_ignored = folder_name
+                        .as_ref()
+                        .is_some_and(|name| ignore.contains(&name.as_str()));
+                    if level < depth && !is_ignored {
+                        pending_dirs.push((entry.path(), level + 1));
+                    }
+                    if let Some(folder_name) = folder_name {
+                        folder_names.insert(folder_name);
+                    }
                     folders.insert(path);
                 } else {
-                    if !path.to_string_lossy().starts_with('.') {
-                        path.extension()
-                            .map(|ext| extensions.insert(ext.to_string_lossy().to_string()));
-                    }
                     if let Some(file_name) = path.file_name() {
-                        file_names.insert(file_name.to_string_lossy().to_string());
+                        let file_name = file_name.to_string_lossy().to_string();
+                        if !file_name.starts_with('.') {
+                            path.extension()
+                                .map(|ext| extensions.insert(ext.to_string_lossy().to_string()));
+                        }
+                        file_names.insert(file_name);
                     }
                     files.insert(path);
                 }
-            });
+            }
+
+            if truncated {
+                log::debug!("Scanning {:?} timed out after {:?}", base, timeout);
+                break;
+            }
+        }
 
         log::trace!(
             "Building HashSets of directory files, folders and extensions took {:?}",
@@ -224,10 +542,13 @@ impl DirContents {
         );
 
         Ok(DirContents {
+            base: base.clone(),
             folders,
+            folder_names,
             files,
             file_names,
             extensions,
+            truncated,
         })
     }
 
@@ -235,10 +556,65 @@ impl DirContents {
         self.files.iter()
     }
 
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Sum the sizes of the files directly in the scanned directory.
+    ///
+    /// Files that can't be read are skipped. Returns `None` if the scan timed out,
+    /// as the total would be missing an unknown number of files.
+    pub fn approx_size_bytes(&self) -> Option<u64> {
+        if self.truncated {
+            return None;
+        }
+
+        let size = self
+            .files
+            .iter()
+            .filter(|path| path.components().count() == 1)
+            .filter_map(|path| fs::metadata(self.base.join(path)).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        Some(size)
+    }
+
     pub fn has_file(&self, path: &str) -> bool {
         self.files.contains(Path::new(path))
     }
 
+    /// Whether any scanned file matches the glob `pattern`, e.g. `src/**/*.rs`.
+    ///
+    /// Patterns are matched against paths relative to the current directory, so
+    /// nested files are only found up to `scan_depth`.
+    pub fn has_file_glob(&self, pattern: &str) -> bool {
+        match glob_to_regex(pattern) {
+            Some(regex) => self
+                .files
+                .iter()
+                .any(|file| regex.is_match(&file.to_slash_lossy())),
+            None => false,
+        }
+    }
+
+    /// Whether any scanned file matches one of the `globs` without also matching
+    /// one of the negated globs, which start with `!`.
+    pub fn has_file_matching_globs(&self, globs: &[&str]) -> bool {
+        let (negated, positive): (Vec<&str>, Vec<&str>) =
+            globs.iter().partition(|glob| glob.starts_with('!'));
+        let positive: Vec<Regex> = positive.into_iter().filter_map(glob_to_regex).collect();
+        let negated: Vec<Regex> = negated
+            .into_iter()
+            .filter_map(|glob| glob_to_regex(&glob[1..]))
+            .collect();
+
+        self.files.iter().any(|file| {
+            let file = file.to_slash_lossy();
+            positive.iter().any(|regex| regex.is_match(&file))
+                && !negated.iter().any(|regex| regex.is_match(&file))
+        })
+    }
+
     pub fn has_file_name(&self, name: &str) -> bool {
         self.file_names.contains(name)
     }
@@ -255,6 +631,15 @@ impl DirContents {
         paths.iter().any(|path| self.has_folder(path))
     }
 
+    /// Whether a folder called `name` was found at any depth of the scan
+    pub fn has_folder_anywhere(&self, name: &str) -> bool {
+        self.folder_names.contains(name)
+    }
+
+    pub fn has_any_folder_anywhere(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| self.has_folder_anywhere(name))
+    }
+
     pub fn has_extension(&self, ext: &str) -> bool {
         self.extensions.contains(ext)
     }
@@ -262,6 +647,18 @@ impl DirContents {
     pub fn has_any_extension(&self, exts: &[&str]) -> bool {
         exts.iter().any(|ext| self.has_extension(ext))
     }
+
+    /// Like `has_extension`, but ignoring case on both sides
+    pub fn has_extension_ci(&self, ext: &str) -> bool {
+        let ext = ext.to_lowercase();
+        self.extensions
+            .iter()
+            .any(|extension| extension.to_lowercase() == ext)
+    }
+
+    pub fn has_any_extension_ci(&self, exts: &[&str]) -> bool {
+        exts.iter().any(|ext| self.has_extension_ci(ext))
+    }
 }
 
 pub struct Repo {
@@ -275,6 +672,221 @@ pub struct Repo {
 
     /// State
     pub state: Option<RepositoryState>,
+
+    /// The repository found when discovering the repo, kept open for the methods
+    /// below. It can't be shared between threads, and modules render in parallel.
+    repository: Option<Mutex<Repository>>,
+}
+
+impl Repo {
+    /// Compares the current branch with the branch it is tracking to determine how
+    /// far ahead or behind it is in relation.
+    ///
+    /// Returns `None` if there is no repo, the repo is bare or the current branch
+    /// has no upstream.
+    pub fn ahead_behind(&self) -> Option<(usize, usize)> {
+        let branch_name = self.branch.as_ref()?;
+        self.ahead_behind_revision(&format!("{}@{{upstream}}", branch_name))
+    }
+
+    /// Compares the current branch with the branch of the same name on `remote`,
+    /// e.g. `upstream/master` rather than the tracked `origin/master`.
+    ///
+    /// Returns `None` if there is no repo, the repo is bare or `remote` has no
+    /// such branch.
+    pub fn ahead_behind_remote(&self, remote: &str) -> Option<(usize, usize)> {
+        let branch_name = self.branch.as_ref()?;
+        self.ahead_behind_revision(&format!("refs/remotes/{}/{}", remote, branch_name))
+    }
+
+    /// Counts the entries of the stash, as recorded in the `refs/stash` reflog.
+    ///
+    /// Returns `None` if there is no repo, the repo is bare or the reflog can't be read.
+    pub fn stash_count(&self) -> Option<usize> {
+        let repository = self.repository()?;
+        let reflog = repository.reflog("refs/stash").ok()?;
+        Some(reflog.len())
+    }
+
+    /// The message and creation time of the most recent stash, read from the
+    /// top entry of the stash reflog.
+    pub fn latest_stash(&self) -> Option<Stash> {
+        let repository = self.repository()?;
+        let reflog = repository.reflog("refs/stash").ok()?;
+        let entry = reflog.get(0)?;
+
+        let stash = Stash {
+            message: parse_stash_message(entry.message()?).to_string(),
+            time: entry.committer().when().seconds(),
+        };
+        Some(stash)
+    }
+
+    /// The `user.name` and `user.email` in effect for the repo, including values
+    /// from the global config and from `includeIf` sections.
+    ///
+    /// Returns `None` if there is no repo or neither value is set.
+    pub fn user_identity(&self) -> Option<(Option<String>, Option<String>)> {
+        let repository = self.repository()?;
+        let config = repository.config().ok()?.snapshot().ok()?;
+
+        let name = config.get_string("user.name").ok();
+        let email = config.get_string("user.email").ok();
+        if name.is_none() && email.is_none() {
+            return None;
+        }
+        Some((name, email))
+    }
+
+    /// The raw fetch URL of the remote the current branch tracks, as configured in
+    /// `remote.<name>.url`.
+    ///
+    /// Returns `None` if there is no repo, HEAD is detached or the branch tracks no
+    /// remote.
+    pub fn remote_url(&self) -> Option<String> {
+        let branch_name = self.branch.as_ref()?;
+        let repository = self.repository()?;
+        let config = repository.config().ok()?.snapshot().ok()?;
+
+        let remote_name = config
+            .get_string(&format!("branch.{}.remote", branch_name))
+            .ok()?;
+        config
+            .get_string(&format!("remote.{}.url", remote_name))
+            .ok()
+    }
+
+    /// Counts the lines added and deleted in the working tree and index, compared
+    /// to HEAD, as `(added, deleted)`.
+    ///
+    /// Returns `None` if there is no repo, the repo is bare or has no commits yet.
+    pub fn diff_line_counts(&self) -> Option<(usize, usize)> {
+        let repository = self.repository()?;
+        if repository.is_bare() {
+            return None;
+        }
+
+        let head_tree = repository.head().ok()?.peel_to_tree().ok()?;
+        let diff = repository
+            .diff_tree_to_workdir_with_index(Some(&head_tree), None)
+            .ok()?;
+        let stats = diff.stats().ok()?;
+        Some((stats.insertions(), stats.deletions()))
+    }
+
+    /// Whether the working tree or index differ from HEAD, counting untracked but
+    /// not ignored files.
+    ///
+    /// libgit2 can't stop its status pass early, so the whole work tree is still
+    /// scanned. Untracked directories aren't recursed into and the entries are
+    /// only checked until the first change, which keeps the rest cheap.
+    ///
+    /// Returns `None` if there is no repo, the repo is bare or its status can't be read.
+    pub fn is_dirty(&self) -> Option<bool> {
+        let repository = self.repository()?;
+        if repository.is_bare() {
+            return None;
+        }
+
+        let mut options = git2::StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(false)
+            .include_ignored(false)
+            .exclude_submodules(true);
+        let statuses = repository.statuses(Some(&mut options)).ok()?;
+        let is_dirty = statuses
+            .iter()
+            .any(|entry| !entry.status().is_ignored() && entry.status() != git2::Status::CURRENT);
+        Some(is_dirty)
+    }
+
+    /// The tag pointing at HEAD if `exact` is true, otherwise the most recent tag
+    /// reachable from HEAD, like `git describe --tags --abbrev=0`.
+    ///
+    /// Returns `None` if there is no repo or no matching tag. If several tags point
+    /// at HEAD, the one that sorts last is used.
+    pub fn tag_at_head(&self, exact: bool) -> Option<String> {
+        let repository = self.repository()?;
+        let head = repository.head().ok()?.peel_to_commit().ok()?.id();
+
+        if !exact {
+            let describe = repository
+                .describe(git2::DescribeOptions::new().describe_tags())
+                .ok()?;
+            return describe
+                .format(Some(git2::DescribeFormatOptions::new().abbreviated_size(0)))
+                .ok();
+        }
+
+        let tags = repository.references_glob("refs/tags/*").ok()?;
+        tags.filter_map(Result::ok)
+            .filter(|reference| {
+                reference
+                    .peel_to_commit()
+                    .is_ok_and(|commit| commit.id() == head)
+            })
+            .filter_map(|reference| reference.shorthand().map(String::from))
+            .max()
+    }
+
+    /// The opened repository, or `None` if there is no repo or it is bare.
+    fn repository(&self) -> Option<MutexGuard<'_, Repository>> {
+        self.root.as_ref()?;
+        self.repository.as_ref()?.lock().ok()
+    }
+
+    fn ahead_behind_revision(&self, revision: &str) -> Option<(usize, usize)> {
+        let branch_name = self.branch.as_ref()?;
+        let repository = self.repository()?;
+
+        let branch_object = repository.revparse_single(branch_name).ok()?;
+        let other_object = repository.revparse_single(revision).ok()?;
+
+        repository
+            .graph_ahead_behind(branch_object.id(), other_object.id())
+            .ok()
+    }
+
+    /// Reads how far along an in-progress rebase is, as `(current, total)` steps.
+    ///
+    /// Returns `None` if there is no repo or no rebase is in progress.
+    pub fn rebase_progress(&self) -> Option<(usize, usize)> {
+        /*
+         *  Sadly, libgit2 seems to have some issues with reading the state of
+         *  interactive rebases. So, instead, we'll poke a few of the .git files
+         *  ourselves. This might be worth re-visiting this in the future...
+         *
+         *  The following is based heavily on: https://github.com/magicmonty/bash-git-prompt
+         */
+        let dot_git = self.repository()?.path().to_path_buf();
+
+        let file_to_usize = |relative_path: &str| {
+            let contents = crate::utils::read_file(dot_git.join(relative_path)).ok()?;
+            contents.trim().parse::<usize>().ok()
+        };
+
+        let (current_path, total_path) = if dot_git.join("rebase-merge").exists() {
+            ("rebase-merge/msgnum", "rebase-merge/end")
+        } else if dot_git.join("rebase-apply").exists() {
+            ("rebase-apply/next", "rebase-apply/last")
+        } else {
+            return None;
+        };
+
+        Some((file_to_usize(current_path)?, file_to_usize(total_path)?))
+    }
+}
+
+pub struct JjRepo {
+    /// The root of the jj workspace, i.e. the directory containing `.jj`.
+    pub root: PathBuf,
+
+    /// The short change id of the working-copy commit, if `jj` could be run.
+    pub change_id: Option<String>,
+
+    /// The first bookmark pointing at the working-copy commit, if any.
+    pub bookmark: Option<String>,
 }
 
 // A struct of Criteria which will be used to verify current PathBuf is
@@ -283,7 +895,10 @@ pub struct ScanDir<'a> {
     dir_contents: &'a DirContents,
     files: &'a [&'a str],
     folders: &'a [&'a str],
+    folders_recursive: &'a [&'a str],
     extensions: &'a [&'a str],
+    extensions_case_insensitive: bool,
+    file_globs: &'a [&'a str],
 }
 
 impl<'a> ScanDir<'a> {
@@ -292,22 +907,204 @@ impl<'a> ScanDir<'a> {
         self
     }
 
+    /// Match files by glob, e.g. `src/**/*.rs`. Globs starting with `!` exclude
+    /// the files they match.
+    pub const fn set_file_globs(mut self, file_globs: &'a [&'a str]) -> Self {
+        self.file_globs = file_globs;
+        self
+    }
+
     pub const fn set_extensions(mut self, extensions: &'a [&'a str]) -> Self {
         self.extensions = extensions;
         self
     }
 
+    /// Match extensions regardless of case, e.g. `Main.CPP` for `cpp`
+    pub const fn set_extensions_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.extensions_case_insensitive = case_insensitive;
+        self
+    }
+
     pub const fn set_folders(mut self, folders: &'a [&'a str]) -> Self {
         self.folders = folders;
         self
     }
 
+    /// Match folders with one of these names at any depth of the scan, rather
+    /// than only paths relative to the current directory
+    pub const fn set_folders_recursive(mut self, folders: &'a [&'a str]) -> Self {
+        self.folders_recursive = folders;
+        self
+    }
+
     /// based on the current Pathbuf check to see
     /// if any of this criteria match or exist and returning a boolean
     pub fn is_match(&self) -> bool {
-        self.dir_contents.has_any_extension(self.extensions)
+        let has_extension = if self.extensions_case_insensitive {
+            self.dir_contents.has_any_extension_ci(self.extensions)
+        } else {
+            self.dir_contents.has_any_extension(self.extensions)
+        };
+
+        has_extension
             || self.dir_contents.has_any_folder(self.folders)
+            || self
+                .dir_contents
+                .has_any_folder_anywhere(self.folders_recursive)
             || self.dir_contents.has_any_file_name(self.files)
+            || self.dir_contents.has_file_matching_globs(self.file_globs)
+    }
+}
+
+/// Translate a glob into an anchored regex. `**/` matches any number of folders,
+/// `*` and `?` match within a single path component.
+fn glob_to_regex(glob: &str) -> Option<Regex> {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    match Regex::new(&regex) {
+        Ok(regex) => Some(regex),
+        Err(error) => {
+            log::debug!("Invalid glob {:?}: {}", glob, error);
+            None
+        }
+    }
+}
+
+/// Parse the duration of the last command, in milliseconds, passed as an argument.
+fn parse_cmd_duration(value: Option<&String>) -> Option<u128> {
+    value?.parse::<u128>().ok()
+}
+
+/// Parse the config passed with `--config-toml`. Invalid TOML is ignored with a
+/// warning, so that the config file is used instead.
+fn parse_inline_config(value: Option<&String>) -> Option<toml::value::Table> {
+    match toml::from_str(value?) {
+        Ok(config) => Some(config),
+        Err(error) => {
+            log::warn!("Unable to parse --config-toml, ignoring it: {}", error);
+            None
+        }
+    }
+}
+
+/// Map a shell or process name, e.g. `zsh`, `-bash` or `pwsh.exe`, to a `Shell`
+fn parse_shell(name: &str) -> Shell {
+    let name = name.trim().trim_start_matches('-').to_lowercase();
+    match name.trim_end_matches(".exe") {
+        "bash" => Shell::Bash,
+        "cmd" => Shell::Cmd,
+        "fish" => Shell::Fish,
+        "ion" => Shell::Ion,
+        "murex" => Shell::Murex,
+        "powershell" | "pwsh" => Shell::PowerShell,
+        "zsh" => Shell::Zsh,
+        _ => Shell::Unknown,
+    }
+}
+
+/// The name of the parent process, read from procfs
+#[cfg(target_os = "linux")]
+fn parent_process_name() -> Option<String> {
+    let parent_id = std::os::unix::process::parent_id();
+    let comm = fs::read_to_string(format!("/proc/{}/comm", parent_id)).ok()?;
+    Some(comm.trim().to_string())
+}
+
+/// The name of the parent process, looked up through sysinfo
+#[cfg(not(target_os = "linux"))]
+fn parent_process_name() -> Option<String> {
+    use sysinfo::{ProcessExt, RefreshKind, SystemExt};
+
+    let mut system = sysinfo::System::new_with_specifics(RefreshKind::new());
+    let pid = sysinfo::get_current_pid().ok()?;
+    system.refresh_process(pid);
+    let parent_id = system.get_process(pid)?.parent()?;
+    system.refresh_process(parent_id);
+    Some(system.get_process(parent_id)?.name().to_string())
+}
+
+/// Parse the number of background jobs passed as an argument, treating missing,
+/// negative or invalid values as no jobs.
+fn parse_jobs(value: Option<&String>) -> i64 {
+    value
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .filter(|&jobs| jobs > 0)
+        .unwrap_or(0)
+}
+
+/// Parse a terminal dimension passed as an argument, falling back to `default` if
+/// it is missing, empty or invalid.
+fn parse_dimension(value: Option<&String>, default: fn() -> usize) -> usize {
+    value
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&value| value > 0)
+        .unwrap_or_else(default)
+}
+
+/// The width of the terminal, preferring a valid `$COLUMNS` over querying the
+/// terminal, which can report bogus sizes in CI or under tmux
+fn default_width() -> usize {
+    width_from_columns(env::var("COLUMNS").ok())
+}
+
+fn width_from_columns(columns: Option<String>) -> usize {
+    parse_dimension(columns.as_ref(), terminal_width)
+}
+
+/// The width reported by the terminal, or 80 columns if it can't be detected
+fn terminal_width() -> usize {
+    term_size::dimensions().map_or(80, |(width, _)| width)
+}
+
+/// The height of the terminal, or 24 rows if it can't be detected
+fn default_height() -> usize {
+    term_size::dimensions().map_or(24, |(_, height)| height)
+}
+
+fn parse_env_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn detect_color_depth(
+    no_color: Option<String>,
+    colorterm: Option<String>,
+    term: Option<String>,
+) -> ColorDepth {
+    if no_color.is_some_and(|value| !value.is_empty()) {
+        return ColorDepth::NoColor;
+    }
+
+    match colorterm.as_deref() {
+        Some("truecolor") | Some("24bit") => return ColorDepth::TrueColor,
+        _ => {}
+    }
+
+    match term.as_deref() {
+        Some("dumb") => ColorDepth::NoColor,
+        Some(term) if term.ends_with("256color") => ColorDepth::Ansi256,
+        _ => ColorDepth::Ansi16,
     }
 }
 
@@ -330,57 +1127,1049 @@ fn get_current_branch(repository: &Repository) -> Option<String> {
     shorthand.map(std::string::ToString::to_string)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Shell {
-    Bash,
-    Fish,
-    Ion,
-    PowerShell,
-    Zsh,
-    Unknown,
-}
+/// Collect the tool versions pinned in `ancestors`, with entries from the nearest
+/// directory winning. In each directory, `mise.toml` takes precedence over
+/// `.tool-versions`, as it does for mise itself.
+fn find_tool_versions<'p>(ancestors: impl Iterator<Item = &'p Path>) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    for ancestor in ancestors {
+        let pins = utils::read_file(ancestor.join("mise.toml"))
+            .map(|content| parse_mise_toml(&content))
+            .into_iter()
+            .chain(
+                utils::read_file(ancestor.join(".tool-versions"))
+                    .map(|content| parse_tool_versions(&content)),
+            );
 
-    fn testdir(paths: &[&str]) -> Result<tempfile::TempDir, std::io::Error> {
-        let dir = tempfile::tempdir()?;
-        for path in paths {
-            let p = dir.path().join(Path::new(path));
-            if let Some(parent) = p.parent() {
-                fs::create_dir_all(parent)?;
-            }
-            fs::File::create(p)?.sync_all()?;
+        for (tool, version) in pins.flatten() {
+            versions.entry(tool).or_insert(version);
         }
-        Ok(dir)
     }
 
-    #[test]
-    fn test_scan_dir() -> Result<(), Box<dyn std::error::Error>> {
-        let empty = testdir(&[])?;
-        let empty_dc = DirContents::from_path(&PathBuf::from(empty.path()))?;
+    versions
+}
 
-        assert_eq!(
-            ScanDir {
-                dir_contents: &empty_dc,
-                files: &["package.json"],
-                extensions: &["js"],
-                folders: &["node_modules"],
-            }
-            .is_match(),
-            false
-        );
-        empty.close()?;
+/// Parse the `tool version [version...]` lines of an asdf `.tool-versions` file
+fn parse_tool_versions(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            Some((words.next()?.to_string(), words.next()?.to_string()))
+        })
+        .collect()
+}
 
-        let rust = testdir(&["README.md", "Cargo.toml", "src/main.rs"])?;
-        let rust_dc = DirContents::from_path(&PathBuf::from(rust.path()))?;
-        assert_eq!(
-            ScanDir {
-                dir_contents: &rust_dc,
+/// Parse the `[tools]` table of a `mise.toml` file, where a version can be a
+/// string, a list of strings or a table with a `version` key
+fn parse_mise_toml(content: &str) -> Vec<(String, String)> {
+    let config: toml::Value = match toml::from_str(content) {
+        Ok(config) => config,
+        Err(e) => {
+            log::debug!("Unable to parse mise.toml: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let tools = match config.get("tools").and_then(toml::Value::as_table) {
+        Some(tools) => tools,
+        None => return Vec::new(),
+    };
+
+    tools
+        .iter()
+        .filter_map(|(tool, value)| {
+            let version = match value {
+                toml::Value::Array(versions) => versions.first()?,
+                toml::Value::Table(options) => options.get("version")?,
+                version => version,
+            };
+            Some((tool.to_string(), version.as_str()?.to_string()))
+        })
+        .collect()
+}
+
+fn contract_home_dir(path: &Path, home_dir: Option<&Path>) -> String {
+    const HOME_SYMBOL: &str = "~";
+
+    // Comparing components rather than strings keeps `/home/user2` from matching `/home/user`
+    let relative = home_dir
+        .filter(|home| home.parent().is_some())
+        .and_then(|home| path.strip_prefix(home).ok());
+
+    match relative {
+        Some(relative) if relative.as_os_str().is_empty() => HOME_SYMBOL.to_string(),
+        Some(relative) => format!("{}/{}", HOME_SYMBOL, relative.to_slash_lossy()),
+        None => path.to_slash_lossy(),
+    }
+}
+
+/// `dir` and its parents, limited to `max_depth` directories unless it is `0`
+fn limit_ancestors(dir: &Path, max_depth: usize) -> impl Iterator<Item = &Path> {
+    let max_depth = match max_depth {
+        0 => usize::MAX,
+        depth => depth,
+    };
+    dir.ancestors().take(max_depth)
+}
+
+/// Walk up `ancestors` looking for the `.jj` directory of a Jujutsu workspace.
+fn find_jj_root<'p>(mut ancestors: impl Iterator<Item = &'p Path>) -> Option<PathBuf> {
+    ancestors
+        .find(|ancestor| ancestor.join(".jj").is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// Ask `jj` for the change id and bookmark of the working-copy commit.
+///
+/// There is no jj library to read the repo with, so this shells out. The working
+/// copy isn't snapshotted, to keep the prompt fast and free of side effects.
+fn get_jj_working_copy(root: &Path) -> (Option<String>, Option<String>) {
+    let output = root.to_str().and_then(|root| {
+        utils::exec_cmd(
+            "jj",
+            &[
+                "--repository",
+                root,
+                "--ignore-working-copy",
+                "--color",
+                "never",
+                "log",
+                "--no-graph",
+                "--revisions",
+                "@",
+                "--template",
+                "change_id.short() ++ \" \" ++ bookmarks.join(\" \")",
+            ],
+        )
+    });
+
+    match output {
+        Some(output) => parse_jj_working_copy(&output.stdout),
+        None => (None, None),
+    }
+}
+
+fn parse_jj_working_copy(output: &str) -> (Option<String>, Option<String>) {
+    let mut words = output.split_whitespace().map(String::from);
+    let change_id = words.next();
+    // Bookmarks that diverged from their remote are suffixed with `*`
+    let bookmark = words
+        .next()
+        .map(|bookmark| bookmark.trim_end_matches('*').to_string());
+    (change_id, bookmark)
+}
+
+fn parse_virtualenv_prompt(prompt: Option<String>, virtual_env: Option<String>) -> Option<String> {
+    let prompt = prompt.map(|prompt| {
+        prompt
+            .trim()
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .trim()
+            .to_string()
+    });
+
+    match prompt {
+        Some(prompt) if !prompt.is_empty() => Some(prompt),
+        _ => {
+            let virtual_env = virtual_env?;
+            let name = Path::new(&virtual_env).file_name()?.to_str()?;
+            Some(name.to_string())
+        }
+    }
+}
+
+/// Detect the container runtime from the `container` variable set by most runtimes,
+/// the marker files Podman and Docker create, and finally the cgroup of PID 1.
+/// `root` is the filesystem root to look for those files under.
+fn detect_container(root: &Path, container_env: Option<String>) -> Option<ContainerKind> {
+    if let Some(container) = container_env.filter(|value| !value.is_empty()) {
+        return Some(match container.as_str() {
+            "docker" => ContainerKind::Docker,
+            "podman" => ContainerKind::Podman,
+            "systemd-nspawn" => ContainerKind::SystemdNspawn,
+            "lxc" | "lxc-libvirt" => ContainerKind::Lxc,
+            _ => ContainerKind::Oci,
+        });
+    }
+
+    if root.join("run/.containerenv").exists() {
+        return Some(ContainerKind::Podman);
+    }
+    if root.join(".dockerenv").exists() {
+        return Some(ContainerKind::Docker);
+    }
+
+    let cgroup = utils::read_file(root.join("proc/1/cgroup")).ok()?;
+    if cgroup.contains("docker") {
+        Some(ContainerKind::Docker)
+    } else if cgroup.contains("libpod") {
+        Some(ContainerKind::Podman)
+    } else if cgroup.contains("lxc") {
+        Some(ContainerKind::Lxc)
+    } else if cgroup.contains("machine.slice") {
+        Some(ContainerKind::SystemdNspawn)
+    } else if cgroup.contains("kubepods") || cgroup.contains("containerd") {
+        Some(ContainerKind::Oci)
+    } else {
+        None
+    }
+}
+
+pub struct Stash {
+    /// The message given when stashing, or git's default "WIP" summary
+    pub message: String,
+    /// When the stash was created, in seconds since the Unix epoch
+    pub time: i64,
+}
+
+/// Strip the "On <branch>: " prefix git adds to custom stash messages
+fn parse_stash_message(message: &str) -> &str {
+    if message.starts_with("On ") {
+        if let Some(index) = message.find(": ") {
+            return &message[index + 2..];
+        }
+    }
+    message
+}
+
+/// How many colors the terminal can display, ordered from fewest to most
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum ColorDepth {
+    NoColor,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+/// Match shell and editor "keymap" names to normalized editing modes
+fn parse_editor_mode(shell: Shell, keymap: &str) -> EditorMode {
+    match (shell, keymap) {
+        // NOTE: in vi mode, fish reports normal mode as "default".
+        // Unfortunately, this is also the name of the non-vi default mode.
+        // We do some environment detection in src/init.rs to translate.
+        // The result: in non-vi fish, keymap is always reported as "insert"
+        (Shell::Fish, "default") => EditorMode::Normal,
+        (Shell::Fish, "visual") => EditorMode::Visual,
+        // ble.sh, a line editor for bash, names its keymaps after vim's mappings.
+        // Its emacs keymap has no modes, so it is treated like insert mode.
+        (Shell::Bash, "vi_imap") | (Shell::Bash, "emacs") => EditorMode::Insert,
+        (Shell::Bash, "vi_nmap") | (Shell::Bash, "vi_omap") => EditorMode::Normal,
+        (Shell::Bash, "vi_xmap") | (Shell::Bash, "vi_smap") => EditorMode::Visual,
+        (_, "viins") | (_, "main") | (_, "insert") => EditorMode::Insert,
+        (_, "vicmd") | (_, "normal") => EditorMode::Normal,
+        // Helix calls its visual mode "select"
+        (_, "select") => EditorMode::Visual,
+        (_, "replace") | (_, "replace_one") => EditorMode::Replace,
+        _ => EditorMode::Unknown,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditorMode {
+    Insert,
+    Normal,
+    Visual,
+    Replace,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shell {
+    Bash,
+    Cmd,
+    Fish,
+    Ion,
+    Murex,
+    PowerShell,
+    Zsh,
+    Unknown,
+}
+
+impl Shell {
+    /// The lowercase name of the shell, as it is passed in `STARSHIP_SHELL`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Cmd => "cmd",
+            Shell::Fish => "fish",
+            Shell::Ion => "ion",
+            Shell::Murex => "murex",
+            Shell::PowerShell => "powershell",
+            Shell::Zsh => "zsh",
+            Shell::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerKind {
+    Docker,
+    Podman,
+    SystemdNspawn,
+    Lxc,
+    /// Any other OCI runtime, e.g. containerd under Kubernetes
+    Oci,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_repo(branch: Option<String>, root: Option<PathBuf>) -> Repo {
+        let repository = root.as_ref().and_then(|root| Repository::open(root).ok());
+        Repo {
+            branch,
+            root,
+            state: None,
+            repository: repository.map(Mutex::new),
+        }
+    }
+
+    fn testdir(paths: &[&str]) -> Result<tempfile::TempDir, std::io::Error> {
+        let dir = tempfile::tempdir()?;
+        for path in paths {
+            let p = dir.path().join(Path::new(path));
+            if let Some(parent) = p.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::File::create(p)?.sync_all()?;
+        }
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_parse_env_bool() {
+        assert_eq!(parse_env_bool("true"), Some(true));
+        assert_eq!(parse_env_bool(" YES\n"), Some(true));
+        assert_eq!(parse_env_bool("1"), Some(true));
+        assert_eq!(parse_env_bool("On"), Some(true));
+        assert_eq!(parse_env_bool("False"), Some(false));
+        assert_eq!(parse_env_bool("0"), Some(false));
+        assert_eq!(parse_env_bool(" no "), Some(false));
+        assert_eq!(parse_env_bool("off"), Some(false));
+        assert_eq!(parse_env_bool(""), None);
+        assert_eq!(parse_env_bool("maybe"), None);
+    }
+
+    #[test]
+    fn test_detect_color_depth() {
+        let env = |value: &str| Some(String::from(value));
+        assert_eq!(
+            detect_color_depth(None, env("truecolor"), env("xterm-256color")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth(None, env("24bit"), None),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth(None, None, env("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_color_depth(None, None, env("xterm")),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(
+            detect_color_depth(None, None, env("dumb")),
+            ColorDepth::NoColor
+        );
+        assert_eq!(
+            detect_color_depth(env("1"), env("truecolor"), env("xterm-256color")),
+            ColorDepth::NoColor
+        );
+        assert_eq!(
+            detect_color_depth(env(""), None, env("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert!(ColorDepth::TrueColor > ColorDepth::Ansi256);
+    }
+
+    #[test]
+    fn test_parse_dimension() {
+        fn default() -> usize {
+            42
+        }
+
+        assert_eq!(parse_dimension(Some(&String::from("120")), default), 120);
+        assert_eq!(parse_dimension(Some(&String::from(" 30 ")), default), 30);
+        assert_eq!(parse_dimension(Some(&String::from("")), default), 42);
+        assert_eq!(parse_dimension(Some(&String::from("0")), default), 42);
+        assert_eq!(parse_dimension(Some(&String::from("wide")), default), 42);
+        assert_eq!(parse_dimension(None, default), 42);
+    }
+
+    #[test]
+    fn test_width_from_columns() {
+        assert_eq!(width_from_columns(Some(String::from("132"))), 132);
+        assert_eq!(
+            width_from_columns(Some(String::from("garbage"))),
+            terminal_width()
+        );
+        assert_eq!(
+            width_from_columns(Some(String::from("-5"))),
+            terminal_width()
+        );
+        assert_eq!(width_from_columns(None), terminal_width());
+    }
+
+    #[test]
+    fn test_find_jj_root() -> Result<(), std::io::Error> {
+        let dir = testdir(&["workspace/.jj/repo/store/type", "workspace/src/lib.rs"])?;
+        let workspace = dir.path().join("workspace");
+
+        let src = workspace.join("src");
+
+        assert_eq!(find_jj_root(src.ancestors()), Some(workspace.clone()));
+        assert_eq!(find_jj_root(workspace.ancestors()), Some(workspace));
+        assert_eq!(find_jj_root(dir.path().ancestors()), None);
+
+        dir.close()
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_contract_home_dir() {
+        let home = Some(Path::new("/home/astronaut"));
+
+        assert_eq!(contract_home_dir(Path::new("/home/astronaut"), home), "~");
+        assert_eq!(
+            contract_home_dir(Path::new("/home/astronaut/schematics/rocket"), home),
+            "~/schematics/rocket"
+        );
+        assert_eq!(
+            contract_home_dir(Path::new("/home/astronaut2/rocket"), home),
+            "/home/astronaut2/rocket"
+        );
+        assert_eq!(contract_home_dir(Path::new("/etc"), home), "/etc");
+        assert_eq!(
+            contract_home_dir(Path::new("/home/astronaut"), Some(Path::new("/"))),
+            "/home/astronaut"
+        );
+        assert_eq!(contract_home_dir(Path::new("/etc"), None), "/etc");
+    }
+
+    #[test]
+    fn test_limit_ancestors() -> Result<(), std::io::Error> {
+        let dir = testdir(&["workspace/.jj/repo/store/type", "workspace/a/b/c/lib.rs"])?;
+        let workspace = dir.path().join("workspace");
+        let deep = workspace.join("a/b/c");
+
+        assert_eq!(limit_ancestors(&deep, 2).count(), 2);
+        assert_eq!(find_jj_root(limit_ancestors(&deep, 3)), None);
+        assert_eq!(
+            find_jj_root(limit_ancestors(&deep, 4)),
+            Some(workspace.clone())
+        );
+        assert_eq!(find_jj_root(limit_ancestors(&deep, 0)), Some(workspace));
+
+        dir.close()
+    }
+
+    #[test]
+    fn test_parse_jj_working_copy() {
+        assert_eq!(
+            parse_jj_working_copy("kxryzmor main* feature\n"),
+            (Some(String::from("kxryzmor")), Some(String::from("main")))
+        );
+        assert_eq!(
+            parse_jj_working_copy("kxryzmor \n"),
+            (Some(String::from("kxryzmor")), None)
+        );
+        assert_eq!(parse_jj_working_copy(""), (None, None));
+    }
+
+    #[test]
+    fn test_parse_virtualenv_prompt() {
+        let venv = || Some(String::from("/foo/bar/my_venv"));
+
+        assert_eq!(
+            parse_virtualenv_prompt(Some(String::from("(my-label) ")), venv()),
+            Some(String::from("my-label"))
+        );
+        assert_eq!(
+            parse_virtualenv_prompt(Some(String::from("project")), venv()),
+            Some(String::from("project"))
+        );
+        assert_eq!(
+            parse_virtualenv_prompt(Some(String::from(" () ")), venv()),
+            Some(String::from("my_venv"))
+        );
+        assert_eq!(
+            parse_virtualenv_prompt(None, venv()),
+            Some(String::from("my_venv"))
+        );
+        assert_eq!(parse_virtualenv_prompt(None, None), None);
+    }
+
+    #[test]
+    fn test_parse_editor_mode() {
+        assert_eq!(parse_editor_mode(Shell::Zsh, "viins"), EditorMode::Insert);
+        assert_eq!(parse_editor_mode(Shell::Zsh, "vicmd"), EditorMode::Normal);
+        assert_eq!(
+            parse_editor_mode(Shell::Fish, "default"),
+            EditorMode::Normal
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Zsh, "default"),
+            EditorMode::Unknown
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Fish, "replace_one"),
+            EditorMode::Replace
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Unknown, "insert"),
+            EditorMode::Insert
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Unknown, "normal"),
+            EditorMode::Normal
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Unknown, "select"),
+            EditorMode::Visual
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Unknown, "isearch"),
+            EditorMode::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_editor_mode_fish() {
+        assert_eq!(
+            parse_editor_mode(Shell::Fish, "default"),
+            EditorMode::Normal
+        );
+        assert_eq!(parse_editor_mode(Shell::Fish, "insert"), EditorMode::Insert);
+        assert_eq!(parse_editor_mode(Shell::Fish, "visual"), EditorMode::Visual);
+        assert_eq!(
+            parse_editor_mode(Shell::Fish, "replace"),
+            EditorMode::Replace
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Fish, "replace_one"),
+            EditorMode::Replace
+        );
+    }
+
+    #[test]
+    fn test_parse_editor_mode_blesh() {
+        assert_eq!(
+            parse_editor_mode(Shell::Bash, "vi_imap"),
+            EditorMode::Insert
+        );
+        assert_eq!(parse_editor_mode(Shell::Bash, "emacs"), EditorMode::Insert);
+        assert_eq!(
+            parse_editor_mode(Shell::Bash, "vi_nmap"),
+            EditorMode::Normal
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Bash, "vi_omap"),
+            EditorMode::Normal
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Bash, "vi_xmap"),
+            EditorMode::Visual
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Bash, "vi_smap"),
+            EditorMode::Visual
+        );
+        assert_eq!(
+            parse_editor_mode(Shell::Zsh, "vi_nmap"),
+            EditorMode::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_cmd_duration() {
+        assert_eq!(parse_cmd_duration(Some(&String::from("2500"))), Some(2500));
+        assert_eq!(parse_cmd_duration(Some(&String::from("-1"))), None);
+        assert_eq!(parse_cmd_duration(Some(&String::from("slow"))), None);
+        assert_eq!(parse_cmd_duration(None), None);
+    }
+
+    #[test]
+    fn test_parse_inline_config() {
+        let config = parse_inline_config(Some(&String::from(
+            "add_newline = false\n[jobs]\nthreshold = 4",
+        )))
+        .unwrap();
+        assert_eq!(config["add_newline"], toml::Value::Boolean(false));
+        assert_eq!(config["jobs"]["threshold"], toml::Value::Integer(4));
+
+        assert_eq!(parse_inline_config(Some(&String::from("[jobs"))), None);
+        assert_eq!(parse_inline_config(None), None);
+    }
+
+    #[test]
+    fn test_set_config() {
+        let mut context = Context::new_with_shell(Shell::Unknown);
+        let config = parse_inline_config(Some(&String::from("[jobs]\ndisabled = true")));
+        context.set_config(config.unwrap());
+        assert!(context.is_module_disabled_in_config("jobs"));
+    }
+
+    #[test]
+    fn test_elapsed() {
+        let context = Context::new_with_shell(Shell::Unknown);
+        let first = context.elapsed();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(context.elapsed() >= first + Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_parse_shell() {
+        assert_eq!(parse_shell("bash"), Shell::Bash);
+        assert_eq!(parse_shell("-zsh"), Shell::Zsh);
+        assert_eq!(parse_shell("fish\n"), Shell::Fish);
+        assert_eq!(parse_shell("pwsh.exe"), Shell::PowerShell);
+        assert_eq!(parse_shell("PowerShell.exe"), Shell::PowerShell);
+        assert_eq!(parse_shell("cmd.exe"), Shell::Cmd);
+        assert_eq!(parse_shell("tmux: server"), Shell::Unknown);
+        assert_eq!(parse_shell(""), Shell::Unknown);
+    }
+
+    #[test]
+    fn test_shell_as_str_round_trip() {
+        let shells = [
+            Shell::Bash,
+            Shell::Cmd,
+            Shell::Fish,
+            Shell::Ion,
+            Shell::Murex,
+            Shell::PowerShell,
+            Shell::Zsh,
+            Shell::Unknown,
+        ];
+        for shell in &shells {
+            assert_eq!(parse_shell(shell.as_str()), *shell);
+        }
+        assert_eq!(Shell::PowerShell.as_str(), "powershell");
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(parse_jobs(Some(&String::from("3"))), 3);
+        assert_eq!(parse_jobs(Some(&String::from(" 2 "))), 2);
+        assert_eq!(parse_jobs(Some(&String::from("-1"))), 0);
+        assert_eq!(parse_jobs(Some(&String::from(""))), 0);
+        assert_eq!(parse_jobs(None), 0);
+    }
+
+    #[test]
+    fn test_jobs_over() {
+        let mut context = Context::new_with_shell(Shell::Unknown);
+        assert!(!context.jobs_over(0));
+
+        context.properties.insert("jobs", String::from("2"));
+        assert!(context.jobs_over(0));
+        assert!(context.jobs_over(1));
+        assert!(!context.jobs_over(2));
+    }
+
+    #[test]
+    fn test_ahead_behind_without_repo() {
+        let repo = test_repo(None, None);
+
+        assert_eq!(repo.ahead_behind(), None);
+        assert_eq!(repo.ahead_behind_remote("upstream"), None);
+    }
+
+    #[test]
+    fn test_stash_count() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let mut repository = Repository::init(dir.path())?;
+        let signature = git2::Signature::now("starship", "starship@example.com")?;
+
+        let file = dir.path().join("readme.md");
+        fs::write(&file, "# starship")?;
+        let mut index = repository.index()?;
+        index.add_path(Path::new("readme.md"))?;
+        let tree = repository.find_tree(index.write_tree()?)?;
+        repository.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[])?;
+        drop(tree);
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+        assert_eq!(repo.stash_count(), Some(0));
+
+        fs::write(&file, "# first")?;
+        repository.stash_save(&signature, "first", None)?;
+        fs::write(&file, "# second")?;
+        repository.stash_save(&signature, "second", None)?;
+        assert_eq!(repo.stash_count(), Some(2));
+
+        let latest = repo.latest_stash().unwrap();
+        assert_eq!(latest.message, "second");
+        assert!(latest.time > 0);
+
+        let bare = test_repo(None, None);
+        assert_eq!(bare.stash_count(), None);
+        assert!(bare.latest_stash().is_none());
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+    #[serde(default)]
+    struct TestModuleConfig {
+        symbol: String,
+        disabled: bool,
+    }
+
+    #[test]
+    fn test_module_config() {
+        let mut context = Context::new_with_shell(Shell::Unknown);
+        let config = parse_inline_config(Some(&String::from(
+            "[rocket]\nsymbol = \"🚀\"\n[broken]\ndisabled = \"yes\"",
+        )));
+        context.set_config(config.unwrap());
+
+        let expected = TestModuleConfig {
+            symbol: String::from("🚀"),
+            disabled: false,
+        };
+        assert_eq!(
+            context.module_config::<TestModuleConfig>("rocket"),
+            expected
+        );
+
+        let invalid: TestModuleConfig = context.module_config("broken");
+        assert_eq!(invalid, TestModuleConfig::default());
+        assert!(!context.is_module_disabled_in_config("broken"));
+
+        let missing: TestModuleConfig = context.module_config("missing");
+        assert_eq!(missing, TestModuleConfig::default());
+    }
+
+    #[test]
+    fn test_parse_tool_versions() {
+        let content = "nodejs 18.16.0 16.20.0\n# a comment\n\npython 3.11.4 # pinned\nruby\n";
+        assert_eq!(
+            parse_tool_versions(content),
+            vec![
+                (String::from("nodejs"), String::from("18.16.0")),
+                (String::from("python"), String::from("3.11.4")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_mise_toml() {
+        let content = r#"
+            [env]
+            NODE_ENV = "development"
+
+            [tools]
+            node = "20"
+            python = ["3.12", "3.11"]
+            ruby = { version = "3.2.2" }
+        "#;
+        let mut pins = parse_mise_toml(content);
+        pins.sort();
+        assert_eq!(
+            pins,
+            vec![
+                (String::from("node"), String::from("20")),
+                (String::from("python"), String::from("3.12")),
+                (String::from("ruby"), String::from("3.2.2")),
+            ]
+        );
+        assert!(parse_mise_toml("not = [valid").is_empty());
+    }
+
+    #[test]
+    fn test_find_tool_versions() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let nested = dir.path().join("nested");
+        fs::create_dir(&nested)?;
+        fs::write(
+            dir.path().join(".tool-versions"),
+            "nodejs 16.20.0\nruby 3.1.0\n",
+        )?;
+        fs::write(
+            nested.join(".tool-versions"),
+            "nodejs 18.16.0\npython 3.10.0\n",
+        )?;
+        fs::write(nested.join("mise.toml"), "[tools]\npython = \"3.12\"\n")?;
+
+        let versions = find_tool_versions(nested.ancestors());
+        assert_eq!(versions.get("nodejs").map(String::as_str), Some("18.16.0"));
+        assert_eq!(versions.get("python").map(String::as_str), Some("3.12"));
+        assert_eq!(versions.get("ruby").map(String::as_str), Some("3.1.0"));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_user_identity() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let mut config = repository.config()?.open_level(git2::ConfigLevel::Local)?;
+        config.set_str("user.name", "Astronaut")?;
+        config.set_str("user.email", "astronaut@example.com")?;
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+        assert_eq!(
+            repo.user_identity(),
+            Some((
+                Some(String::from("Astronaut")),
+                Some(String::from("astronaut@example.com"))
+            ))
+        );
+
+        let no_repo = test_repo(None, None);
+        assert_eq!(no_repo.user_identity(), None);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_remote_url() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let mut config = repository.config()?.open_level(git2::ConfigLevel::Local)?;
+        config.set_str("remote.origin.url", "git@github.com:starship/starship.git")?;
+        config.set_str("branch.master.remote", "origin")?;
+
+        let tracking = test_repo(Some(String::from("master")), Some(dir.path().to_path_buf()));
+        assert_eq!(
+            tracking.remote_url(),
+            Some(String::from("git@github.com:starship/starship.git"))
+        );
+
+        let untracked = test_repo(
+            Some(String::from("feature")),
+            Some(dir.path().to_path_buf()),
+        );
+        assert_eq!(untracked.remote_url(), None);
+
+        let detached = test_repo(Some(String::from("HEAD")), Some(dir.path().to_path_buf()));
+        assert_eq!(detached.remote_url(), None);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_line_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let signature = git2::Signature::now("starship", "starship@example.com")?;
+
+        let file = dir.path().join("readme.md");
+        fs::write(&file, "# starship\nThe minimal prompt\n")?;
+        let mut index = repository.index()?;
+        index.add_path(Path::new("readme.md"))?;
+        index.write()?;
+        let tree = repository.find_tree(index.write_tree()?)?;
+        repository.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[])?;
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+        assert_eq!(repo.diff_line_counts(), Some((0, 0)));
+
+        fs::write(
+            &file,
+            "# starship\nThe minimal, blazing fast prompt\nfor any shell\n",
+        )?;
+        assert_eq!(repo.diff_line_counts(), Some((2, 1)));
+
+        // Staged changes are still counted against HEAD
+        index.add_path(Path::new("readme.md"))?;
+        index.write()?;
+        assert_eq!(repo.diff_line_counts(), Some((2, 1)));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_dirty() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let signature = git2::Signature::now("starship", "starship@example.com")?;
+
+        let file = dir.path().join("readme.md");
+        fs::write(&file, "# starship")?;
+        let mut index = repository.index()?;
+        index.add_path(Path::new("readme.md"))?;
+        index.write()?;
+        let tree = repository.find_tree(index.write_tree()?)?;
+        repository.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[])?;
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+        assert_eq!(repo.is_dirty(), Some(false));
+
+        fs::write(&file, "# changed")?;
+        assert_eq!(repo.is_dirty(), Some(true));
+
+        fs::write(&file, "# starship")?;
+        assert_eq!(repo.is_dirty(), Some(false));
+
+        let no_repo = test_repo(None, None);
+        assert_eq!(no_repo.is_dirty(), None);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_container_env() {
+        let dir = testdir(&[]).unwrap();
+        let detect = |value: &str| detect_container(dir.path(), Some(value.to_string()));
+        assert_eq!(detect("docker"), Some(ContainerKind::Docker));
+        assert_eq!(detect("podman"), Some(ContainerKind::Podman));
+        assert_eq!(detect("systemd-nspawn"), Some(ContainerKind::SystemdNspawn));
+        assert_eq!(detect("lxc"), Some(ContainerKind::Lxc));
+        assert_eq!(detect("oci"), Some(ContainerKind::Oci));
+        assert_eq!(detect(""), None);
+        assert_eq!(detect_container(dir.path(), None), None);
+    }
+
+    #[test]
+    fn test_detect_container_marker_files() {
+        let podman = testdir(&["run/.containerenv", ".dockerenv"]).unwrap();
+        assert_eq!(
+            detect_container(podman.path(), None),
+            Some(ContainerKind::Podman)
+        );
+
+        let docker = testdir(&[".dockerenv"]).unwrap();
+        assert_eq!(
+            detect_container(docker.path(), None),
+            Some(ContainerKind::Docker)
+        );
+    }
+
+    #[test]
+    fn test_detect_container_cgroup() {
+        let dir = testdir(&[]).unwrap();
+        fs::create_dir_all(dir.path().join("proc/1")).unwrap();
+        let detect = |cgroup: &str| {
+            fs::write(dir.path().join("proc/1/cgroup"), cgroup).unwrap();
+            detect_container(dir.path(), None)
+        };
+        assert_eq!(
+            detect("12:pids:/docker/0123abcd\n"),
+            Some(ContainerKind::Docker)
+        );
+        assert_eq!(
+            detect("0::/machine.slice/machine-dev.scope\n"),
+            Some(ContainerKind::SystemdNspawn)
+        );
+        assert_eq!(
+            detect("0::/kubepods/besteffort/pod1234\n"),
+            Some(ContainerKind::Oci)
+        );
+        assert_eq!(detect("0::/init.scope\n"), None);
+    }
+
+    #[test]
+    fn test_tag_at_head() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let signature = git2::Signature::now("starship", "starship@example.com")?;
+        let tree = repository.find_tree(repository.index()?.write_tree()?)?;
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+
+        let first = repository.commit(Some("HEAD"), &signature, &signature, "First", &tree, &[])?;
+        assert_eq!(repo.tag_at_head(true), None);
+        assert_eq!(repo.tag_at_head(false), None);
+
+        let first = repository.find_commit(first)?;
+        repository.tag_lightweight("v0.1.0", first.as_object(), false)?;
+        assert_eq!(repo.tag_at_head(true), Some(String::from("v0.1.0")));
+        assert_eq!(repo.tag_at_head(false), Some(String::from("v0.1.0")));
+
+        repository.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "Second",
+            &tree,
+            &[&first],
+        )?;
+        assert_eq!(repo.tag_at_head(true), None);
+        assert_eq!(repo.tag_at_head(false), Some(String::from("v0.1.0")));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_stash_message() {
+        assert_eq!(
+            parse_stash_message("On master: fix the build"),
+            "fix the build"
+        );
+        assert_eq!(
+            parse_stash_message("WIP on master: 1a2b3c4 Initial"),
+            "WIP on master: 1a2b3c4 Initial"
+        );
+    }
+
+    #[test]
+    fn test_rebase_progress() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let repository = Repository::init(dir.path())?;
+        let rebase_merge = repository.path().join("rebase-merge");
+
+        let repo = test_repo(None, Some(dir.path().to_path_buf()));
+        assert_eq!(repo.rebase_progress(), None);
+
+        fs::create_dir(&rebase_merge)?;
+        fs::write(rebase_merge.join("msgnum"), "3\n")?;
+        fs::write(rebase_merge.join("end"), "12\n")?;
+        assert_eq!(repo.rebase_progress(), Some((3, 12)));
+
+        fs::remove_dir_all(&rebase_merge)?;
+        assert_eq!(repo.rebase_progress(), None);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_dir() -> Result<(), Box<dyn std::error::Error>> {
+        let empty = testdir(&[])?;
+        let empty_dc = DirContents::from_path(&PathBuf::from(empty.path()))?;
+
+        assert_eq!(
+            ScanDir {
+                dir_contents: &empty_dc,
                 files: &["package.json"],
                 extensions: &["js"],
                 folders: &["node_modules"],
+                folders_recursive: &[],
+                extensions_case_insensitive: false,
+                file_globs: &[],
+            }
+            .is_match(),
+            false
+        );
+        empty.close()?;
+
+        let rust = testdir(&["README.md", "Cargo.toml", "src/main.rs"])?;
+        let rust_dc = DirContents::from_path(&PathBuf::from(rust.path()))?;
+        assert_eq!(
+            ScanDir {
+                dir_contents: &rust_dc,
+                files: &["package.json"],
+                extensions: &["js"],
+                folders: &["node_modules"],
+                folders_recursive: &[],
+                extensions_case_insensitive: false,
+                file_globs: &[],
             }
             .is_match(),
             false
@@ -395,6 +2184,9 @@ mod tests {
                 files: &["package.json"],
                 extensions: &["js"],
                 folders: &["node_modules"],
+                folders_recursive: &[],
+                extensions_case_insensitive: false,
+                file_globs: &[],
             }
             .is_match(),
             false
@@ -409,6 +2201,9 @@ mod tests {
                 files: &["package.json"],
                 extensions: &["js"],
                 folders: &["node_modules"],
+                folders_recursive: &[],
+                extensions_case_insensitive: false,
+                file_globs: &[],
             }
             .is_match(),
             true
@@ -417,4 +2212,189 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_scan_dir_extensions_case_insensitive() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["Main.CPP", "App.CsProj"])?;
+        let dir_contents = DirContents::from_path(&PathBuf::from(dir.path()))?;
+
+        assert!(!dir_contents.has_extension("cpp"));
+        assert!(dir_contents.has_extension_ci("cpp"));
+        assert!(dir_contents.has_extension_ci("CSPROJ"));
+        assert!(!dir_contents.has_extension_ci("java"));
+
+        let scan = |extensions_case_insensitive: bool| {
+            ScanDir {
+                dir_contents: &dir_contents,
+                files: &[],
+                extensions: &["csproj"],
+                folders: &[],
+                folders_recursive: &[],
+                extensions_case_insensitive,
+                file_globs: &[],
+            }
+            .is_match()
+        };
+        assert!(!scan(false));
+        assert!(scan(true));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_file_glob() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&[
+            "Cargo.toml",
+            "src/main.rs",
+            "src/modules/rust.rs",
+            "tests/testsuite/main.rs",
+        ])?;
+        let path = PathBuf::from(dir.path());
+        let contents = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 3, &[])?;
+
+        assert!(contents.has_file_glob("src/**/*.rs"));
+        assert!(contents.has_file_glob("src/*.rs"));
+        assert!(contents.has_file_glob("**/rust.rs"));
+        assert!(contents.has_file_glob("Cargo.to?l"));
+        assert!(!contents.has_file_glob("*.rs"));
+        assert!(!contents.has_file_glob("src/**/*.py"));
+
+        assert!(contents.has_file_matching_globs(&["**/*.rs", "!tests/**"]));
+        assert!(!contents.has_file_matching_globs(&["tests/**/*.rs", "!**/main.rs"]));
+        assert!(!contents.has_file_matching_globs(&["!src/**"]));
+
+        let scan = |file_globs: &[&str]| {
+            ScanDir {
+                dir_contents: &contents,
+                files: &[],
+                extensions: &[],
+                folders: &[],
+                folders_recursive: &[],
+                extensions_case_insensitive: false,
+                file_globs,
+            }
+            .is_match()
+        };
+        assert!(scan(&["src/**/*.rs"]));
+        assert!(!scan(&["src/**/*.rs", "!src/**"]));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_dir_folders_recursive() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["README.md", "infra/prod/.terraform/terraform.tfstate"])?;
+        let path = PathBuf::from(dir.path());
+
+        let deep = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 3, &[])?;
+        assert!(deep.has_folder_anywhere(".terraform"));
+        assert!(deep.has_folder_anywhere("prod"));
+        assert!(!deep.has_folder(".terraform"));
+        assert!(!deep.has_folder_anywhere("infra/prod"));
+
+        let scan = |dir_contents: &DirContents, folders_recursive: &[&str]| {
+            ScanDir {
+                dir_contents,
+                files: &[],
+                extensions: &[],
+                folders: &[".terraform"],
+                folders_recursive,
+                extensions_case_insensitive: false,
+                file_globs: &[],
+            }
+            .is_match()
+        };
+        assert!(!scan(&deep, &[]));
+        assert!(scan(&deep, &[".terraform"]));
+
+        // Folders deeper than the scan depth aren't found
+        let shallow = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 2, &[])?;
+        assert!(!scan(&shallow, &[".terraform"]));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_dir_contents_scan_depth() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["README.md", "src/pom.xml", "src/com/test/Main.java"])?;
+        let path = PathBuf::from(dir.path());
+
+        let shallow = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 1, &[])?;
+        assert!(shallow.has_folder("src"));
+        assert!(!shallow.has_folder("src/com"));
+        assert!(!shallow.has_file("src/pom.xml"));
+        assert!(!shallow.has_file_name("pom.xml"));
+        assert!(!shallow.has_extension("xml"));
+
+        let nested = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 2, &[])?;
+        assert!(nested.has_file("README.md"));
+        assert!(nested.has_folder("src"));
+        assert!(nested.has_folder("src/com"));
+        assert!(nested.has_file("src/pom.xml"));
+        assert!(nested.has_file_name("pom.xml"));
+        assert!(nested.has_extension("xml"));
+        assert!(!nested.has_folder("src/com/test"));
+        assert!(!nested.has_extension("java"));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_dir_contents_scan_ignore() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["package.json", "node_modules/left-pad/package.json"])?;
+        let path = PathBuf::from(dir.path());
+
+        let contents = DirContents::from_path_with_timeout(
+            &path,
+            Duration::from_secs(30),
+            3,
+            &["node_modules"],
+        )?;
+        assert!(contents.has_file("package.json"));
+        assert!(contents.has_folder("node_modules"));
+        assert!(!contents.has_folder("node_modules/left-pad"));
+        assert!(!contents.has_file("node_modules/left-pad/package.json"));
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_dir_contents_truncated() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = testdir(&["README.md", "package.json"])?;
+        let path = PathBuf::from(dir.path());
+
+        let complete = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 1, &[])?;
+        assert!(!complete.truncated);
+        assert!(complete.has_file("package.json"));
+
+        let timed_out = DirContents::from_path_with_timeout(&path, Duration::from_secs(0), 1, &[])?;
+        assert!(timed_out.truncated);
+        assert!(!timed_out.has_file("package.json"));
+        assert_eq!(timed_out.approx_size_bytes(), None);
+
+        dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_dir_contents_size() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("small.txt"), "starship")?;
+        fs::write(dir.path().join("large.txt"), vec![b'x'; 1024])?;
+        fs::create_dir(dir.path().join("nested"))?;
+        fs::write(dir.path().join("nested").join("ignored.txt"), "ignored")?;
+        let path = PathBuf::from(dir.path());
+
+        let contents = DirContents::from_path_with_timeout(&path, Duration::from_secs(30), 2, &[])?;
+        assert_eq!(contents.file_count(), 3);
+        assert_eq!(contents.approx_size_bytes(), Some(1032));
+
+        dir.close()?;
+        Ok(())
+    }
 }
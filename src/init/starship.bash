@@ -34,14 +34,17 @@ starship_precmd() {
     # Run the bash precmd function, if it's set. If not set, evaluates to no-op
     "${starship_precmd_user_func-:}"
 
+    # Pass the ble.sh keymap along, if ble.sh is in use. Expands to nothing otherwise.
+    local STARSHIP_KEYMAP=${_ble_decode_keymap:+"--keymap=$_ble_decode_keymap"}
+
     # Prepare the timer data, if needed.
     if [[ $STARSHIP_START_TIME ]]; then
         STARSHIP_END_TIME=$(::STARSHIP:: time)
         STARSHIP_DURATION=$((STARSHIP_END_TIME - STARSHIP_START_TIME))
-        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)" --cmd-duration=$STARSHIP_DURATION)"
+        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)" --pid=$$ --cmd-duration=$STARSHIP_DURATION $STARSHIP_KEYMAP)"
         unset STARSHIP_START_TIME
     else
-        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)")"
+        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)" --pid=$$ $STARSHIP_KEYMAP)"
     fi
     PREEXEC_READY=true;  # Signal that we can safely restart the timer
 }
@@ -8,7 +8,12 @@ function fish_prompt
     set -l exit_code $status
     # Account for changes in variable name between v2.7 and v3.0
     set -l starship_duration "$CMD_DURATION$cmd_duration"
-    ::STARSHIP:: prompt --status=$exit_code --keymap=$keymap --cmd-duration=$starship_duration --jobs=(count (jobs -p))
+    # $fish_pid only exists since v3.0, older versions expand %self instead
+    set -l starship_pid $fish_pid
+    if test -z "$starship_pid"
+        set starship_pid %self
+    end
+    ::STARSHIP:: prompt --status=$exit_code --keymap=$keymap --cmd-duration=$starship_duration --jobs=(count (jobs -p)) --pid=$starship_pid
 end
 
 # disable virtualenv prompt, it breaks starship
@@ -56,8 +56,8 @@ fn main() {
         .short("k")
         .long("keymap")
         .value_name("KEYMAP")
-        // fish/zsh only
-        .help("The keymap of fish/zsh")
+        // fish/zsh/ble.sh only
+        .help("The keymap of fish/zsh/ble.sh")
         .takes_value(true);
 
     let jobs_arg = Arg::with_name("jobs")
@@ -67,6 +67,31 @@ fn main() {
         .help("The number of currently running jobs")
         .takes_value(true);
 
+    let pid_arg = Arg::with_name("pid")
+        .long("pid")
+        .value_name("PID")
+        .help("The process ID of the current shell")
+        .takes_value(true);
+
+    let config_toml_arg = Arg::with_name("config_toml")
+        .long("config-toml")
+        .value_name("TOML")
+        .help("An inline configuration to use instead of the configuration file")
+        .takes_value(true);
+
+    let terminal_width_arg = Arg::with_name("terminal_width")
+        .short("w")
+        .long("terminal-width")
+        .value_name("WIDTH")
+        .help("The width of the current interactive terminal.")
+        .takes_value(true);
+
+    let terminal_height_arg = Arg::with_name("terminal_height")
+        .long("terminal-height")
+        .value_name("HEIGHT")
+        .help("The height of the current interactive terminal.")
+        .takes_value(true);
+
     let init_scripts_arg = Arg::with_name("print_full_init")
         .long("print-full-init")
         .help("Print the main initialization script (as opposed to the init stub)");
@@ -93,7 +118,11 @@ fn main() {
                     .arg(&path_arg)
                     .arg(&cmd_duration_arg)
                     .arg(&keymap_arg)
-                    .arg(&jobs_arg),
+                    .arg(&jobs_arg)
+                    .arg(&pid_arg)
+                    .arg(&config_toml_arg)
+                    .arg(&terminal_width_arg)
+                    .arg(&terminal_height_arg),
             )
             .subcommand(
                 SubCommand::with_name("module")
@@ -114,7 +143,11 @@ fn main() {
                     .arg(&path_arg)
                     .arg(&cmd_duration_arg)
                     .arg(&keymap_arg)
-                    .arg(&jobs_arg),
+                    .arg(&jobs_arg)
+                    .arg(&pid_arg)
+                    .arg(&config_toml_arg)
+                    .arg(&terminal_width_arg)
+                    .arg(&terminal_height_arg),
             )
             .subcommand(
                 SubCommand::with_name("config")
@@ -10,25 +10,34 @@ use std::fmt;
 // Keep these ordered alphabetically.
 // Default ordering is handled in configs/mod.rs
 pub const ALL_MODULES: &[&str] = &[
+    "android",
+    "app_env",
     "aws",
     #[cfg(feature = "battery")]
     "battery",
+    "buf",
     "character",
     "cmd_duration",
     "conda",
+    "container",
+    "devbox",
     "directory",
     "docker_context",
     "dotnet",
+    "earthly",
     "elixir",
     "elm",
     "erlang",
     "env_var",
     "git_branch",
     "git_commit",
+    "git_fetch",
+    "git_metrics",
     "git_state",
     "git_status",
     "golang",
     "haskell",
+    "helmfile",
     "hg_branch",
     "hostname",
     "java",
@@ -36,19 +45,26 @@ pub const ALL_MODULES: &[&str] = &[
     "julia",
     "kubernetes",
     "line_break",
+    "locale",
     "memory_usage",
+    "nix_profile",
     "nix_shell",
     "nodejs",
     "package",
+    "pid",
     "python",
     "ruby",
     "crystal",
     "rust",
     "php",
+    "sops",
+    "systemd",
+    "task_runner",
     "terraform",
     "singularity",
     "time",
     "username",
+    "zellij",
 ];
 
 /// A module is a collection of segments showing data for a single integration
@@ -145,6 +161,17 @@ impl<'a> Module<'a> {
         self
     }
 
+    /// Removes the styling of the module, its affixes and all of its segments,
+    /// so that it is rendered without any color escapes.
+    pub fn clear_styles(&mut self) {
+        self.style = Style::default();
+        self.prefix.set_style(Style::default());
+        self.suffix.set_style(Style::default());
+        for segment in &mut self.segments {
+            segment.style = None;
+        }
+    }
+
     /// Returns a vector of colored ANSIString elements to be later used with
     /// `ANSIStrings()` to optimize ANSI codes
     pub fn ansi_strings(&self) -> Vec<ANSIString> {
@@ -164,6 +191,7 @@ impl<'a> Module<'a> {
         ansi_strings = match shell {
             Shell::Bash => ansi_strings_modified(ansi_strings, shell),
             Shell::Zsh => ansi_strings_modified(ansi_strings, shell),
+            Shell::Murex => ansi_strings_modified(ansi_strings, shell),
             _ => ansi_strings,
         };
 
@@ -290,4 +318,19 @@ mod tests {
 
         assert!(module.is_empty());
     }
+
+    #[test]
+    fn test_module_clear_styles() {
+        let name = "unit_test";
+        let mut module = Module::new(name, "This is a unit test", None);
+        module.set_style(Style::new().bold());
+        module.get_prefix().set_style(Style::new().italic());
+        module
+            .create_segment("test_segment", &SegmentConfig::new("test"))
+            .set_style(ansi_term::Color::Red);
+
+        module.clear_styles();
+
+        assert_eq!(module.to_string(), "via test ");
+    }
 }
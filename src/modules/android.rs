@@ -0,0 +1,203 @@
+use regex::Regex;
+use std::path::Path;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::android::AndroidConfig;
+use crate::utils;
+
+const GRADLE_FILES: &[&str] = &[
+    "build.gradle",
+    "build.gradle.kts",
+    "app/build.gradle",
+    "app/build.gradle.kts",
+];
+const MANIFEST_FILES: &[&str] = &[
+    "AndroidManifest.xml",
+    "src/main/AndroidManifest.xml",
+    "app/src/main/AndroidManifest.xml",
+];
+
+/// Creates a module with the compile and target SDK versions of an Android project
+///
+/// Will display the SDK versions if the current directory contains a gradle build
+/// (`build.gradle`, `build.gradle.kts`, `settings.gradle` or `settings.gradle.kts`)
+/// and an `AndroidManifest.xml`, either at the top level, in `src/main` or
+/// `app/src/main`, or anywhere within `scan_depth`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_gradle_project = context
+        .try_begin_scan()?
+        .set_files(&[
+            "build.gradle",
+            "build.gradle.kts",
+            "settings.gradle",
+            "settings.gradle.kts",
+        ])
+        .is_match();
+    let has_manifest = MANIFEST_FILES
+        .iter()
+        .any(|file| context.current_dir.join(file).is_file())
+        || context
+            .dir_contents()
+            .is_ok_and(|contents| contents.has_file_glob("**/AndroidManifest.xml"));
+
+    if !is_gradle_project || !has_manifest {
+        return None;
+    }
+
+    let mut module = context.new_module("android");
+    let config: AndroidConfig = AndroidConfig::try_load(module.config);
+    module.set_style(config.style);
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+
+    let sdk_versions = get_sdk_versions(&context.current_dir);
+    if let Some(compile_sdk) = &sdk_versions.compile_sdk {
+        module.create_segment(
+            "compile_sdk",
+            &SegmentConfig::new(&format!("sdk {}", compile_sdk)),
+        );
+    }
+    if let Some(target_sdk) = &sdk_versions.target_sdk {
+        module.create_segment(
+            "target_sdk",
+            &SegmentConfig::new(&format!(" target {}", target_sdk)),
+        );
+    }
+
+    Some(module)
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct SdkVersions {
+    compile_sdk: Option<String>,
+    target_sdk: Option<String>,
+}
+
+/// Read the SDK versions from the first gradle build file that declares any
+fn get_sdk_versions(dir: &Path) -> SdkVersions {
+    GRADLE_FILES
+        .iter()
+        .filter_map(|file| utils::read_file(dir.join(file)).ok())
+        .map(|content| parse_sdk_versions(&content))
+        .find(|versions| versions.compile_sdk.is_some() || versions.target_sdk.is_some())
+        .unwrap_or_default()
+}
+
+/// Extract `compileSdk` and `targetSdk` from a Groovy or Kotlin gradle file,
+/// accepting the older `compileSdkVersion`/`targetSdkVersion` names too
+fn parse_sdk_versions(gradle_file: &str) -> SdkVersions {
+    let sdk_version = |name: &str| {
+        let pattern = format!(r"(?m)^\s*{}(?:Version)?\s*[=(]?\s*(\d+)", name);
+        let re = Regex::new(&pattern).unwrap();
+        re.captures(gradle_file)
+            .map(|captures| captures[1].to_string())
+    };
+
+    SdkVersions {
+        compile_sdk: sdk_version("compileSdk"),
+        target_sdk: sdk_version("targetSdk"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn test_parse_sdk_versions() {
+        let groovy = "android {\n    compileSdkVersion 33\n    defaultConfig {\n        targetSdkVersion 31\n    }\n}\n";
+        assert_eq!(
+            parse_sdk_versions(groovy),
+            SdkVersions {
+                compile_sdk: Some(String::from("33")),
+                target_sdk: Some(String::from("31")),
+            }
+        );
+
+        let kotlin = "android {\n    compileSdk = 34\n    defaultConfig {\n        targetSdk = 34\n        minSdk = 24\n    }\n}\n";
+        assert_eq!(
+            parse_sdk_versions(kotlin),
+            SdkVersions {
+                compile_sdk: Some(String::from("34")),
+                target_sdk: Some(String::from("34")),
+            }
+        );
+
+        let variable = "android {\n    compileSdk rootProject.ext.compileSdk\n}\n";
+        assert_eq!(parse_sdk_versions(variable), SdkVersions::default());
+    }
+
+    #[test]
+    fn folder_without_android_manifest() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("build.gradle"))?.sync_all()?;
+        let actual = render_module("android", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_android_project() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("settings.gradle"), "include ':app'\n")?;
+        fs::create_dir_all(dir.path().join("app/src/main"))?;
+        File::create(dir.path().join("app/src/main/AndroidManifest.xml"))?.sync_all()?;
+        fs::write(
+            dir.path().join("app/build.gradle"),
+            "android {\n    compileSdk 34\n    defaultConfig {\n        targetSdk 33\n    }\n}\n",
+        )?;
+
+        let actual = render_module("android", dir.path(), None);
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("🤖 sdk 34 target 33")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_nested_android_manifest() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("build.gradle"), "android {}\n")?;
+        fs::create_dir_all(dir.path().join("mobile/src/main"))?;
+        File::create(dir.path().join("mobile/src/main/AndroidManifest.xml"))?.sync_all()?;
+
+        let actual = render_module("android", dir.path(), None);
+        assert_eq!(None, actual);
+
+        let actual = render_module(
+            "android",
+            dir.path(),
+            Some(toml::toml! {
+                scan_depth = 4
+            }),
+        );
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🤖 ")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_unparseable_sdk_versions() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("build.gradle"), "android {}\n")?;
+        File::create(dir.path().join("AndroidManifest.xml"))?.sync_all()?;
+
+        let actual = render_module("android", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🤖 ")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -0,0 +1,62 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::app_env::AppEnvConfig;
+
+/// Creates a module with the active application environment
+///
+/// Will display the value of the first set variable in `variables`, e.g.
+/// `$RAILS_ENV`. The style depends on whether the value names a production or a
+/// development environment.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("app_env");
+    let config: AppEnvConfig = AppEnvConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let app_env = config
+        .variables
+        .iter()
+        .filter_map(|variable| context.get_env(variable))
+        .find(|value| !value.trim().is_empty())?;
+    let app_env = app_env.trim();
+
+    let style = if matches_any(app_env, &config.production_values) {
+        config.production_style
+    } else if matches_any(app_env, &config.development_values) {
+        config.development_style
+    } else {
+        config.style
+    };
+
+    module.set_style(style);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    module.create_segment("app_env", &SegmentConfig::new(app_env));
+
+    Some(module)
+}
+
+fn matches_any(value: &str, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_any() {
+        let production = ["production", "prod"];
+        assert!(matches_any("production", &production));
+        assert!(matches_any("PROD", &production));
+        assert!(!matches_any("staging", &production));
+    }
+}
@@ -7,6 +7,7 @@ use std::str::FromStr;
 
 use dirs::home_dir;
 
+use super::utils::region::short_region;
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::aws::{AwsConfig, AwsItems};
@@ -79,8 +80,9 @@ fn get_aws_region() -> Option<Region> {
     }
 }
 
-fn alias_region(region: &str, aliases: &HashMap<String, &str>) -> String {
+fn alias_region(region: &str, aliases: &HashMap<String, &str>, short: bool) -> String {
     match aliases.get(region) {
+        None if short => short_region(region).unwrap_or_else(|| region.to_string()),
         None => region.to_string(),
         Some(alias) => (*alias).to_string(),
     }
@@ -96,16 +98,25 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.get_prefix().set_value(AWS_PREFIX);
 
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     match config.displayed_items {
         AwsItems::All => {
             let (aws_profile, aws_region) = get_aws_profile_and_region();
 
             let aws_segment = match (&aws_profile, &aws_region) {
                 (None, None) => return None,
-                (Some(p), Some(r)) => format!("{}({})", p, alias_region(r, &config.region_aliases)),
+                (Some(p), Some(r)) => format!(
+                    "{}({})",
+                    p,
+                    alias_region(r, &config.region_aliases, config.short_region)
+                ),
                 (Some(p), None) => p.to_string(),
-                (None, Some(r)) => alias_region(r, &config.region_aliases),
+                (None, Some(r)) => alias_region(r, &config.region_aliases, config.short_region),
             };
             module.create_segment("all", &config.region.with_value(&aws_segment));
         }
@@ -115,7 +126,11 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.create_segment("profile", &config.profile.with_value(&aws_profile));
         }
         AwsItems::Region => {
-            let aws_region = alias_region(&get_aws_region()?, &config.region_aliases);
+            let aws_region = alias_region(
+                &get_aws_region()?,
+                &config.region_aliases,
+                config.short_region,
+            );
 
             module.create_segment("region", &config.region.with_value(&aws_region));
         }
@@ -0,0 +1,153 @@
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::buf::BufConfig;
+use crate::utils;
+
+/// Creates a module with the current buf version and Protobuf module name
+///
+/// Will display the buf version if the current directory contains a `buf.yaml`,
+/// `buf.work.yaml` or `buf.gen.yaml` file. Workspaces, which have a `buf.work.yaml`,
+/// are marked with `workspace_symbol`. The module name is read from the `name` of
+/// `buf.yaml`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_buf_project = context
+        .try_begin_scan()?
+        .set_files(&["buf.yaml", "buf.work.yaml", "buf.gen.yaml"])
+        .is_match();
+
+    if !is_buf_project {
+        return None;
+    }
+
+    let buf_version = utils::exec_cmd("buf", &["--version"])?.combined();
+    let formatted_version = format_buf_version(&buf_version)?;
+
+    let mut module = context.new_module("buf");
+    let config: BufConfig = BufConfig::try_load(module.config);
+    module.set_style(config.style);
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+
+    let dir_contents = context.dir_contents().ok()?;
+    if dir_contents.has_file("buf.work.yaml") {
+        module.create_segment("workspace_symbol", &config.workspace_symbol);
+    }
+
+    module.create_segment("version", &SegmentConfig::new(&formatted_version));
+
+    if config.show_module_name {
+        let module_name = utils::read_file(context.current_dir.join("buf.yaml"))
+            .ok()
+            .and_then(|content| parse_module_name(&content));
+        if let Some(module_name) = module_name {
+            module.create_segment(
+                "module_name",
+                &SegmentConfig::new(&format!(" ({})", module_name)),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+/// Format the output of `buf --version`, e.g. `1.28.1`
+fn format_buf_version(buf_version: &str) -> Option<String> {
+    let version = buf_version.trim();
+    if version.is_empty() {
+        return None;
+    }
+    Some(format!("v{}", version.trim_start_matches('v')))
+}
+
+/// Read the module name from a `buf.yaml`
+///
+/// Version 1 configs declare a top-level `name`, while version 2 configs list
+/// modules, in which case the name of the first one is used.
+fn parse_module_name(content: &str) -> Option<String> {
+    let docs = YamlLoader::load_from_str(content).ok()?;
+    let doc = docs.first()?;
+
+    doc["name"]
+        .as_str()
+        .or_else(|| doc["modules"][0]["name"].as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn test_format_buf_version() {
+        assert_eq!(
+            format_buf_version("1.28.1\n"),
+            Some(String::from("v1.28.1"))
+        );
+        assert_eq!(format_buf_version(""), None);
+    }
+
+    #[test]
+    fn test_parse_module_name() {
+        let v1 = "version: v1\nname: buf.build/acme/weather\n";
+        assert_eq!(
+            parse_module_name(v1),
+            Some(String::from("buf.build/acme/weather"))
+        );
+
+        let v2 = "version: v2\nmodules:\n  - path: proto\n    name: buf.build/acme/petapis\n";
+        assert_eq!(
+            parse_module_name(v2),
+            Some(String::from("buf.build/acme/petapis"))
+        );
+
+        assert_eq!(parse_module_name("version: v1\n"), None);
+    }
+
+    #[test]
+    fn folder_without_buf_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module("buf", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_buf_yaml() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("buf.yaml"),
+            "version: v1\nname: buf.build/acme/weather\n",
+        )?;
+        let actual = render_module("buf", dir.path(), None);
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue
+                .bold()
+                .paint("🐃 v1.28.1 (buf.build/acme/weather)")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_buf_work_yaml() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("buf.work.yaml"))?.sync_all()?;
+        let actual = render_module("buf", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐃 ⊞ v1.28.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
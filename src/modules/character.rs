@@ -1,5 +1,6 @@
-use super::{Context, Module, RootModuleConfig, Shell};
+use super::{Context, Module, RootModuleConfig};
 use crate::configs::character::CharacterConfig;
+use crate::context::EditorMode;
 
 /// Creates a module for the prompt character
 ///
@@ -10,13 +11,6 @@ use crate::configs::character::CharacterConfig;
 /// - If the exit-code was anything else, the arrow will be formatted with
 /// `style_failure` (red by default)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    enum ShellEditMode {
-        Normal,
-        Insert,
-    };
-    const ASSUMED_MODE: ShellEditMode = ShellEditMode::Insert;
-    // TODO: extend config to more modes
-
     let mut module = context.new_module("character");
     let config: CharacterConfig = CharacterConfig::try_load(module.config);
     module.get_prefix().set_value("");
@@ -24,20 +18,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let props = &context.properties;
     let exit_code_default = std::string::String::from("0");
     let exit_code = props.get("status_code").unwrap_or(&exit_code_default);
-    let keymap_default = std::string::String::from("viins");
-    let keymap = props.get("keymap").unwrap_or(&keymap_default);
     let exit_success = exit_code == "0";
 
-    // Match shell "keymap" names to normalized vi modes
-    // NOTE: in vi mode, fish reports normal mode as "default".
-    // Unfortunately, this is also the name of the non-vi default mode.
-    // We do some environment detection in src/init.rs to translate.
-    // The result: in non-vi fish, keymap is always reported as "insert"
-    let mode = match (&context.shell, keymap.as_str()) {
-        (Shell::Fish, "default") | (Shell::Zsh, "vicmd") => ShellEditMode::Normal,
-        _ => ASSUMED_MODE,
-    };
-
     if exit_success {
         module.set_style(config.style_success);
     } else {
@@ -49,9 +31,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     if config.use_symbol_for_status && !exit_success {
         module.create_segment("error_symbol", &config.error_symbol)
     } else {
-        match mode {
-            ShellEditMode::Normal => module.create_segment("vicmd_symbol", &config.vicmd_symbol),
-            ShellEditMode::Insert => module.create_segment("symbol", &config.symbol),
+        match context.editor_mode() {
+            EditorMode::Normal => module.create_segment("vicmd_symbol", &config.vicmd_symbol),
+            EditorMode::Visual => module.create_segment("visual_symbol", &config.visual_symbol),
+            EditorMode::Replace => module.create_segment("replace_symbol", &config.replace_symbol),
+            EditorMode::Insert | EditorMode::Unknown => {
+                module.create_segment("symbol", &config.symbol)
+            }
         }
     };
 
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Local};
+
 use super::{Context, Module, SegmentConfig};
 
 use crate::config::RootModuleConfig;
@@ -11,12 +13,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("cmd_duration");
     let config: CmdDurationConfig = CmdDurationConfig::try_load(module.config);
 
-    let props = &context.properties;
-    let elapsed = props
-        .get("cmd_duration")
-        .unwrap_or(&"invalid_time".into())
-        .parse::<u128>()
-        .ok()?;
+    let elapsed = context.cmd_duration_millis()?;
 
     /* TODO: Once error handling is implemented, warn the user if their config
     min time is nonsensical */
@@ -38,37 +35,72 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_style(module_color);
     module.create_segment(
         "cmd_duration",
-        &SegmentConfig::new(&render_time(elapsed, config.show_milliseconds)),
+        &SegmentConfig::new(&match config.zero_pad {
+            true => render_time_padded(elapsed, config.show_milliseconds),
+            false => render_time(elapsed, config.show_milliseconds),
+        }),
     );
+    if config.show_completed_at {
+        let completed_at = format_completed_at(config.completed_format, Local::now());
+        module.create_segment(
+            "completed_at",
+            &SegmentConfig::new(&format!(" at {}", completed_at)),
+        );
+    }
     module.get_prefix().set_value(config.prefix);
 
     Some(module)
 }
 
 // Render the time into a nice human-readable string
-fn render_time(raw_millis: u128, show_millis: bool) -> String {
-    // Calculate a simple breakdown into days/hours/minutes/seconds/milliseconds
-    let (millis, raw_seconds) = (raw_millis % 1000, raw_millis / 1000);
-    let (seconds, raw_minutes) = (raw_seconds % 60, raw_seconds / 60);
-    let (minutes, raw_hours) = (raw_minutes % 60, raw_minutes / 60);
-    let (hours, days) = (raw_hours % 24, raw_hours / 24);
+pub fn render_time(raw_millis: u128, show_millis: bool) -> String {
+    let (components, millis) = time_components(raw_millis);
+
+    let mut rendered_components: Vec<String> =
+        components.iter().map(render_time_component).collect();
+    if show_millis || raw_millis < 1000 {
+        rendered_components.push(render_time_component(&(millis, "ms")));
+    }
+    rendered_components.join("")
+}
 
-    let components = [days, hours, minutes, seconds];
-    let suffixes = ["d", "h", "m", "s"];
+// Render the time like `render_time`, but with every component after the first
+// non-zero one zero-padded, so that the width only changes with the magnitude
+pub fn render_time_padded(raw_millis: u128, show_millis: bool) -> String {
+    let (components, millis) = time_components(raw_millis);
 
     let mut rendered_components: Vec<String> = components
         .iter()
-        .zip(&suffixes)
-        .map(render_time_component)
+        .skip_while(|(component, _)| *component == 0)
+        .map(|(component, suffix)| format!("{:02}{}", component, suffix))
         .collect();
     if show_millis || raw_millis < 1000 {
-        rendered_components.push(render_time_component((&millis, &"ms")));
+        rendered_components.push(format!("{:03}ms", millis));
     }
     rendered_components.join("")
 }
 
+/// Format when the last command completed. The prompt is rendered right after a
+/// command finishes, so this is passed the current time.
+fn format_completed_at(completed_format: &str, now: DateTime<Local>) -> String {
+    now.format(completed_format).to_string()
+}
+
+// Calculate a simple breakdown into days/hours/minutes/seconds, plus the remaining milliseconds
+fn time_components(raw_millis: u128) -> ([(u128, &'static str); 4], u128) {
+    let (millis, raw_seconds) = (raw_millis % 1000, raw_millis / 1000);
+    let (seconds, raw_minutes) = (raw_seconds % 60, raw_seconds / 60);
+    let (minutes, raw_hours) = (raw_minutes % 60, raw_minutes / 60);
+    let (hours, days) = (raw_hours % 24, raw_hours / 24);
+
+    (
+        [(days, "d"), (hours, "h"), (minutes, "m"), (seconds, "s")],
+        millis,
+    )
+}
+
 /// Render a single component of the time string, giving an empty string if component is zero
-fn render_time_component((component, suffix): (&u128, &&str)) -> String {
+fn render_time_component((component, suffix): &(u128, &str)) -> String {
     match component {
         0 => String::new(),
         n => format!("{}{}", n, suffix),
@@ -78,6 +110,7 @@ fn render_time_component((component, suffix): (&u128, &&str)) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::offset::TimeZone;
 
     #[test]
     fn test_500ms() {
@@ -99,4 +132,37 @@ mod tests {
     fn test_1d() {
         assert_eq!(render_time(86_400_000 as u128, true), "1d")
     }
+    #[test]
+    fn test_500ms_padded() {
+        assert_eq!(render_time_padded(500 as u128, true), "500ms")
+    }
+    #[test]
+    fn test_5s_padded() {
+        assert_eq!(render_time_padded(5_000 as u128, false), "05s")
+    }
+    #[test]
+    fn test_10s_padded() {
+        assert_eq!(render_time_padded(10_000 as u128, true), "10s000ms")
+    }
+    #[test]
+    fn test_90s_padded() {
+        assert_eq!(render_time_padded(90_000 as u128, true), "01m30s000ms")
+    }
+    #[test]
+    fn test_10110s_padded() {
+        assert_eq!(render_time_padded(10_110_000 as u128, false), "02h48m30s")
+    }
+    #[test]
+    fn test_1d_padded() {
+        assert_eq!(
+            render_time_padded(86_400_000 as u128, false),
+            "01d00h00m00s"
+        )
+    }
+    #[test]
+    fn test_completed_at() {
+        let now = Local.ymd(2014, 7, 8).and_hms(13, 37, 5);
+        assert_eq!(format_completed_at("%T", now), "13:37:05");
+        assert_eq!(format_completed_at("%H:%M", now), "13:37");
+    }
 }
@@ -8,10 +8,17 @@ use crate::configs::conda::CondaConfig;
 
 /// Creates a module with the current Conda environment
 ///
-/// Will display the Conda environment iff `$CONDA_DEFAULT_ENV` is set.
+/// Will display the Conda environment iff `$CONDA_DEFAULT_ENV` is set. Environments
+/// activated by mamba or micromamba, which set `$MAMBA_EXE`, get their own symbol.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // Reference implementation: https://github.com/denysdovhan/spaceship-prompt/blob/master/sections/conda.zsh
-    let conda_env = env::var("CONDA_DEFAULT_ENV").unwrap_or_else(|_| "".into());
+    let is_mamba = env::var("MAMBA_EXE").is_ok_and(|exe| !exe.trim().is_empty());
+    let conda_env = if is_mamba {
+        env::var("MAMBA_DEFAULT_ENV").or_else(|_| env::var("CONDA_DEFAULT_ENV"))
+    } else {
+        env::var("CONDA_DEFAULT_ENV")
+    }
+    .unwrap_or_else(|_| "".into());
     if conda_env.trim().is_empty() {
         return None;
     }
@@ -23,7 +30,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.set_style(config.style);
 
-    module.create_segment("symbol", &config.symbol);
+    let symbol = if is_mamba {
+        &config.mamba_symbol
+    } else {
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol)
+    };
+    module.create_segment("symbol", symbol);
     module.create_segment("environment", &config.environment.with_value(&conda_env));
 
     Some(module)
@@ -0,0 +1,40 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::container::ContainerConfig;
+use crate::context::ContainerKind;
+
+/// Creates a module with the container runtime starship is running in
+///
+/// Will display the runtime if `$container` is set, Podman's or Docker's marker
+/// file exists, or the cgroup of PID 1 belongs to a container runtime.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("container");
+    let config: ContainerConfig = ContainerConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let kind = context.container_kind()?;
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("in ");
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "kind",
+        &SegmentConfig::new(&format!("[{}]", container_name(kind))),
+    );
+
+    Some(module)
+}
+
+fn container_name(kind: ContainerKind) -> &'static str {
+    match kind {
+        ContainerKind::Docker => "docker",
+        ContainerKind::Podman => "podman",
+        ContainerKind::SystemdNspawn => "systemd-nspawn",
+        ContainerKind::Lxc => "lxc",
+        ContainerKind::Oci => "oci",
+    }
+}
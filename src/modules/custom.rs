@@ -21,8 +21,14 @@ pub fn module<'a>(name: &'a str, context: &'a Context) -> Option<Module<'a>> {
 
     let mut scan_dir = context.try_begin_scan()?;
 
-    if !config.files.0.is_empty() {
-        scan_dir = scan_dir.set_files(&config.files.0);
+    // Entries with glob characters are matched as paths, the rest as file names
+    let (file_globs, files): (Vec<&str>, Vec<&str>) =
+        config.files.0.iter().partition(|file| is_glob(file));
+    if !files.is_empty() {
+        scan_dir = scan_dir.set_files(&files);
+    }
+    if !file_globs.is_empty() {
+        scan_dir = scan_dir.set_file_globs(&file_globs);
     }
     if !config.extensions.0.is_empty() {
         scan_dir = scan_dir.set_extensions(&config.extensions.0);
@@ -33,9 +39,15 @@ pub fn module<'a>(name: &'a str, context: &'a Context) -> Option<Module<'a>> {
 
     let mut is_match = scan_dir.is_match();
 
+    let env = if config.pass_context {
+        context_env(context)
+    } else {
+        Vec::new()
+    };
+
     if !is_match {
         if let Some(when) = config.when {
-            is_match = exec_when(when, config.shell);
+            is_match = exec_when(when, config.shell, &env);
         }
 
         if !is_match {
@@ -57,7 +69,7 @@ pub fn module<'a>(name: &'a str, context: &'a Context) -> Option<Module<'a>> {
         module.create_segment("symbol", &symbol);
     }
 
-    if let Some(output) = exec_command(config.command, config.shell) {
+    if let Some(output) = exec_command(config.command, config.shell, &env) {
         let trimmed = output.trim();
 
         if trimmed.is_empty() {
@@ -75,6 +87,53 @@ pub fn module<'a>(name: &'a str, context: &'a Context) -> Option<Module<'a>> {
     }
 }
 
+fn is_glob(file: &str) -> bool {
+    file.starts_with('!') || file.contains(['*', '?'])
+}
+
+/// Describe the prompt's context to the command through environment variables,
+/// so that scripts don't have to work it out again
+fn context_env(context: &Context) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        (
+            "STARSHIP_CWD",
+            context.current_dir.to_string_lossy().to_string(),
+        ),
+        ("STARSHIP_SHELL", context.shell.as_str().to_string()),
+        ("STARSHIP_TERM_WIDTH", context.width.to_string()),
+        ("STARSHIP_TERM_HEIGHT", context.height.to_string()),
+    ];
+
+    if let Ok(dir_contents) = context.dir_contents() {
+        env.push(("STARSHIP_DIR_FILES", dir_contents.file_count().to_string()));
+    }
+    if let Some(size) = context.dir_size_bytes() {
+        env.push(("STARSHIP_DIR_SIZE", size.to_string()));
+    }
+
+    if let Ok(repo) = context.get_repo() {
+        if let Some(branch) = &repo.branch {
+            env.push(("STARSHIP_GIT_BRANCH", branch.clone()));
+        }
+        if let Some(root) = &repo.root {
+            env.push(("STARSHIP_GIT_ROOT", root.to_string_lossy().to_string()));
+        }
+        if let Some(url) = repo.remote_url() {
+            env.push(("STARSHIP_GIT_REMOTE_URL", url));
+        }
+        if let Some((name, email)) = repo.user_identity() {
+            if let Some(name) = name {
+                env.push(("STARSHIP_GIT_USER_NAME", name));
+            }
+            if let Some(email) = email {
+                env.push(("STARSHIP_GIT_USER_EMAIL", email));
+            }
+        }
+    }
+
+    env
+}
+
 /// Return the invoking shell, using `shell` and fallbacking in order to STARSHIP_SHELL and "sh"
 #[cfg(not(windows))]
 fn get_shell(shell: Option<&str>) -> std::borrow::Cow<str> {
@@ -89,8 +148,9 @@ fn get_shell(shell: Option<&str>) -> std::borrow::Cow<str> {
 
 /// Attempt to run the given command in a shell by passing it as `stdin` to `get_shell()`
 #[cfg(not(windows))]
-fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
+fn shell_command(cmd: &str, shell: Option<&str>, env: &[(&str, String)]) -> Option<Output> {
     let command = Command::new(get_shell(shell).as_ref())
+        .envs(env.iter().cloned())
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -105,6 +165,7 @@ fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
 
             Command::new("/bin/env")
                 .arg("sh")
+                .envs(env.iter().cloned())
                 .stdin(Stdio::piped())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
@@ -120,7 +181,7 @@ fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
 /// Attempt to run the given command in a shell by passing it as `stdin` to `get_shell()`,
 /// or by invoking cmd.exe /C.
 #[cfg(windows)]
-fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
+fn shell_command(cmd: &str, shell: Option<&str>, env: &[(&str, String)]) -> Option<Output> {
     let shell = if let Some(shell) = shell {
         Some(std::borrow::Cow::Borrowed(shell))
     } else if let Ok(env_shell) = std::env::var("STARSHIP_SHELL") {
@@ -131,6 +192,7 @@ fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
 
     if let Some(forced_shell) = shell {
         let command = Command::new(forced_shell.as_ref())
+            .envs(env.iter().cloned())
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -150,6 +212,7 @@ fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
     let command = Command::new("cmd.exe")
         .arg("/C")
         .arg(cmd)
+        .envs(env.iter().cloned())
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -159,10 +222,10 @@ fn shell_command(cmd: &str, shell: Option<&str>) -> Option<Output> {
 }
 
 /// Execute the given command capturing all output, and return whether it return 0
-fn exec_when(cmd: &str, shell: Option<&str>) -> bool {
+fn exec_when(cmd: &str, shell: Option<&str>, env: &[(&str, String)]) -> bool {
     log::trace!("Running '{}'", cmd);
 
-    if let Some(output) = shell_command(cmd, shell) {
+    if let Some(output) = shell_command(cmd, shell, env) {
         if !output.status.success() {
             log::trace!("non-zero exit code '{:?}'", output.status.code());
             log::trace!(
@@ -184,10 +247,10 @@ fn exec_when(cmd: &str, shell: Option<&str>) -> bool {
 }
 
 /// Execute the given command, returning its output on success
-fn exec_command(cmd: &str, shell: Option<&str>) -> Option<String> {
+fn exec_command(cmd: &str, shell: Option<&str>, env: &[(&str, String)]) -> Option<String> {
     log::trace!("Running '{}'", cmd);
 
-    if let Some(output) = shell_command(cmd, shell) {
+    if let Some(output) = shell_command(cmd, shell, env) {
         if !output.status.success() {
             log::trace!("Non-zero exit code '{:?}'", output.status.code());
             log::trace!(
@@ -210,6 +273,8 @@ fn exec_command(cmd: &str, shell: Option<&str>) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::StarshipConfig;
+    use crate::context::Shell;
 
     #[cfg(not(windows))]
     const SHELL: Option<&'static str> = Some("/bin/sh");
@@ -225,21 +290,24 @@ mod tests {
 
     #[test]
     fn when_returns_right_value() {
-        assert!(exec_when("echo hello", SHELL));
-        assert!(!exec_when(FAILING_COMMAND, SHELL));
+        assert!(exec_when("echo hello", SHELL, &[]));
+        assert!(!exec_when(FAILING_COMMAND, SHELL, &[]));
     }
 
     #[test]
     fn when_returns_false_if_invalid_command() {
-        assert!(!exec_when(UNKNOWN_COMMAND, SHELL));
+        assert!(!exec_when(UNKNOWN_COMMAND, SHELL, &[]));
     }
 
     #[test]
     #[cfg(not(windows))]
     fn command_returns_right_string() {
-        assert_eq!(exec_command("echo hello", SHELL), Some("hello\n".into()));
         assert_eq!(
-            exec_command("echo 강남스타일", SHELL),
+            exec_command("echo hello", SHELL, &[]),
+            Some("hello\n".into())
+        );
+        assert_eq!(
+            exec_command("echo 강남스타일", SHELL, &[]),
             Some("강남스타일\n".into())
         );
     }
@@ -247,9 +315,12 @@ mod tests {
     #[test]
     #[cfg(windows)]
     fn command_returns_right_string() {
-        assert_eq!(exec_command("echo hello", SHELL), Some("hello\r\n".into()));
         assert_eq!(
-            exec_command("echo 강남스타일", SHELL),
+            exec_command("echo hello", SHELL, &[]),
+            Some("hello\r\n".into())
+        );
+        assert_eq!(
+            exec_command("echo 강남스타일", SHELL, &[]),
             Some("강남스타일\r\n".into())
         );
     }
@@ -258,11 +329,11 @@ mod tests {
     #[cfg(not(windows))]
     fn command_ignores_stderr() {
         assert_eq!(
-            exec_command("echo foo 1>&2; echo bar", SHELL),
+            exec_command("echo foo 1>&2; echo bar", SHELL, &[]),
             Some("bar\n".into())
         );
         assert_eq!(
-            exec_command("echo foo; echo bar 1>&2", SHELL),
+            exec_command("echo foo; echo bar 1>&2", SHELL, &[]),
             Some("foo\n".into())
         );
     }
@@ -271,18 +342,146 @@ mod tests {
     #[cfg(windows)]
     fn command_ignores_stderr() {
         assert_eq!(
-            exec_command("echo foo 1>&2 & echo bar", SHELL),
+            exec_command("echo foo 1>&2 & echo bar", SHELL, &[]),
             Some("bar\r\n".into())
         );
         assert_eq!(
-            exec_command("echo foo& echo bar 1>&2", SHELL),
+            exec_command("echo foo& echo bar 1>&2", SHELL, &[]),
             Some("foo\r\n".into())
         );
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn command_receives_env() {
+        let env = [("STARSHIP_CWD", String::from("/tmp/rocket"))];
+        assert_eq!(
+            exec_command("echo $STARSHIP_CWD", SHELL, &env),
+            Some("/tmp/rocket\n".into())
+        );
+        assert!(exec_when("test -n \"$STARSHIP_CWD\"", SHELL, &env));
+        assert!(!exec_when("test -n \"$STARSHIP_CWD\"", SHELL, &[]));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn pass_context_sets_cwd() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                [custom.cwd]
+                command = "echo $STARSHIP_CWD"
+                when = "true"
+                shell = "/bin/sh"
+                pass_context = true
+            }),
+        };
+
+        let actual = module("cwd", &context).map(|module| module.to_string());
+        let expected = context.current_dir.to_string_lossy().to_string();
+        assert!(actual.unwrap().contains(&expected));
+
+        dir.close()
+    }
+
+    #[test]
+    fn context_env_has_shell() {
+        let context = Context::new_with_shell(Shell::Fish);
+        let env = context_env(&context);
+        assert!(env.contains(&("STARSHIP_SHELL", String::from("fish"))));
+    }
+
+    #[test]
+    fn context_env_has_terminal_size() {
+        let mut context = Context::new_with_shell(Shell::Unknown);
+        context.width = 120;
+        context.height = 40;
+
+        let env = context_env(&context);
+        assert!(env.contains(&("STARSHIP_TERM_WIDTH", String::from("120"))));
+        assert!(env.contains(&("STARSHIP_TERM_HEIGHT", String::from("40"))));
+    }
+
+    #[test]
+    fn context_env_has_dir_stats() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("rocket.txt"), "liftoff")?;
+        std::fs::write(dir.path().join("fuel.txt"), "lox")?;
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+
+        let env = context_env(&context);
+        assert!(env.contains(&("STARSHIP_DIR_FILES", String::from("2"))));
+        assert!(env.contains(&("STARSHIP_DIR_SIZE", String::from("10"))));
+
+        dir.close()
+    }
+
+    #[test]
+    fn context_env_has_git_identity() -> Result<(), git2::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = git2::Repository::init(dir.path())?;
+        let mut config = repository.config()?;
+        config.set_str("user.name", "Yuri Gagarin")?;
+        config.set_str("user.email", "yuri@vostok.su")?;
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+
+        let env = context_env(&context);
+        assert!(env.contains(&("STARSHIP_GIT_USER_NAME", String::from("Yuri Gagarin"))));
+        assert!(env.contains(&("STARSHIP_GIT_USER_EMAIL", String::from("yuri@vostok.su"))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn context_env_has_git_remote_url() -> Result<(), git2::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let repository = git2::Repository::init_opts(
+            dir.path(),
+            git2::RepositoryInitOptions::new().initial_head("master"),
+        )?;
+        let mut config = repository.config()?;
+        config.set_str("branch.master.remote", "origin")?;
+        config.set_str("remote.origin.url", "git@github.com:starship/starship.git")?;
+        let context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+
+        let env = context_env(&context);
+        assert!(env.contains(&(
+            "STARSHIP_GIT_REMOTE_URL",
+            String::from("git@github.com:starship/starship.git")
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn files_can_be_globs() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("rocket.yml"), "")?;
+        let mut context = Context::new_with_dir(clap::ArgMatches::default(), dir.path());
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                [custom.yaml]
+                command = "echo yaml"
+                files = ["*.yml", "!secrets.yml"]
+                shell = "/bin/sh"
+
+                [custom.secrets]
+                command = "echo secrets"
+                files = ["secret?.yml"]
+                shell = "/bin/sh"
+            }),
+        };
+
+        assert!(module("yaml", &context).is_some());
+        assert!(module("secrets", &context).is_none());
+
+        dir.close()
+    }
+
     #[test]
     fn command_can_fail() {
-        assert_eq!(exec_command(FAILING_COMMAND, SHELL), None);
-        assert_eq!(exec_command(UNKNOWN_COMMAND, SHELL), None);
+        assert_eq!(exec_command(FAILING_COMMAND, SHELL, &[]), None);
+        assert_eq!(exec_command(UNKNOWN_COMMAND, SHELL, &[]), None);
     }
 }
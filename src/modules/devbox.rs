@@ -0,0 +1,111 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::devbox::DevboxConfig;
+use crate::utils;
+
+/// Creates a module showing the Devbox environment of the current directory
+///
+/// Will display if the current directory contains a `devbox.json` or `devbox.lock`
+/// file, or if a devbox shell is active (`$DEVBOX_SHELL_ENABLED`). Active shells
+/// use `shell_style`. The number of packages is read from `devbox.json`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_shell_active = context.get_env_bool("DEVBOX_SHELL_ENABLED") == Some(true);
+    let is_devbox_project = context
+        .try_begin_scan()?
+        .set_files(&["devbox.json", "devbox.lock"])
+        .is_match();
+
+    if !is_shell_active && !is_devbox_project {
+        return None;
+    }
+
+    let mut module = context.new_module("devbox");
+    let config: DevboxConfig = DevboxConfig::try_load(module.config);
+
+    if is_shell_active {
+        module.set_style(config.shell_style);
+    } else {
+        module.set_style(config.style);
+    }
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+
+    if config.show_packages {
+        let packages = utils::read_file(context.current_dir.join("devbox.json"))
+            .ok()
+            .and_then(|content| count_packages(&content));
+        if let Some(packages) = packages {
+            let unit = if packages == 1 { "package" } else { "packages" };
+            module.create_segment(
+                "packages",
+                &SegmentConfig::new(&format!("{} {}", packages, unit)),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+/// Count the `packages` of a `devbox.json`, which are either a list such as
+/// `["go@1.21", "nodejs"]` or a map from package names to versions
+fn count_packages(content: &str) -> Option<usize> {
+    let devbox_json: serde_json::Value = serde_json::from_str(content).ok()?;
+    match &devbox_json["packages"] {
+        serde_json::Value::Array(packages) => Some(packages.len()),
+        serde_json::Value::Object(packages) => Some(packages.len()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn test_count_packages() {
+        assert_eq!(
+            count_packages(r#"{"packages": ["go@1.21", "nodejs@20", "ripgrep"]}"#),
+            Some(3)
+        );
+        assert_eq!(
+            count_packages(r#"{"packages": {"go": "1.21", "nodejs": "latest"}}"#),
+            Some(2)
+        );
+        assert_eq!(count_packages(r#"{"shell": {}}"#), None);
+        assert_eq!(count_packages("not json"), None);
+    }
+
+    #[test]
+    fn folder_without_devbox_files() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module("devbox", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_devbox_json() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("devbox.json"),
+            r#"{"packages": ["go@1.21", "nodejs@20", "ripgrep"]}"#,
+        )?;
+        let actual = render_module("devbox", dir.path(), None);
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("📦 3 packages")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
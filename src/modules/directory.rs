@@ -14,18 +14,15 @@ use crate::configs::directory::DirectoryConfig;
 /// **Contraction**
 ///     - Paths beginning with the home directory or with a git repo right
 /// inside the home directory will be contracted to `~`
-///     - Paths containing a git repo will contract to begin at the repo root
+///     - Paths containing a git repo or a Jujutsu workspace will contract to begin
+/// at its root
 ///
 /// **Truncation**
 /// Paths will be limited in length to `3` path components by default.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    const HOME_SYMBOL: &str = "~";
-
     let mut module = context.new_module("directory");
     let config: DirectoryConfig = DirectoryConfig::try_load(module.config);
 
-    module.set_style(config.style);
-
     // Using environment PWD is the standard approach for determining logical path
     // If this is None for any reason, we fall back to reading the os-provided path
     let physical_current_dir = if config.use_logical_path {
@@ -54,9 +51,21 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let home_dir = dirs::home_dir().unwrap();
     log::debug!("Current directory: {:?}", current_dir);
 
+    let in_home = is_in_home(current_dir, &home_dir);
+    match config.system_style {
+        Some(system_style) if !in_home => module.set_style(system_style),
+        _ => module.set_style(config.style),
+    };
+
     let repo = &context.get_repo().ok()?;
 
-    let dir_string = match &repo.root {
+    // A Jujutsu workspace without a colocated git repo has no git root
+    let repo_root = repo
+        .root
+        .as_ref()
+        .or_else(|| context.get_jj_repo().map(|jj_repo| &jj_repo.root));
+
+    let dir_string = match repo_root {
         Some(repo_root) if config.truncate_to_repo && (repo_root != &home_dir) => {
             let repo_folder_name = repo_root.file_name().unwrap().to_str().unwrap();
 
@@ -64,15 +73,32 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             contract_path(current_dir, repo_root, repo_folder_name)
         }
         // Contract the path to the home directory
-        _ => contract_path(current_dir, &home_dir, HOME_SYMBOL),
+        _ => context.contract_home(current_dir),
+    };
+
+    let collapse = |dir_string: String| {
+        if config.collapse_repeated {
+            collapse_repeated_segments(&dir_string, config.collapse_repeated_symbol)
+        } else {
+            dir_string
+        }
     };
 
     // Truncate the dir string to the maximum number of path components
-    let truncated_dir_string = truncate(dir_string, config.truncation_length as usize);
+    let truncated_dir_string = truncate(collapse(dir_string), config.truncation_length as usize);
+
+    let location_symbol = if in_home {
+        config.home_symbol
+    } else {
+        config.system_symbol
+    };
+    if !location_symbol.is_empty() {
+        module.create_segment("symbol", &SegmentConfig::new(location_symbol));
+    }
 
     if config.fish_style_pwd_dir_length > 0 {
         // If user is using fish style path, we need to add the segment first
-        let contracted_home_dir = contract_path(&current_dir, &home_dir, HOME_SYMBOL);
+        let contracted_home_dir = collapse(context.contract_home(current_dir));
         let fish_style_dir = to_fish_style(
             config.fish_style_pwd_dir_length as usize,
             contracted_home_dir,
@@ -101,6 +127,11 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+/// Whether `path` is the home directory or one of its subdirectories
+fn is_in_home(path: &Path, home_dir: &Path) -> bool {
+    path.starts_with(home_dir)
+}
+
 /// Contract the root component of a path
 ///
 /// Replaces the `top_level_path` in a given `full_path` with the provided
@@ -126,6 +157,30 @@ fn contract_path(full_path: &Path, top_level_path: &Path, top_level_replacement:
     )
 }
 
+/// Collapse runs of identically named path components into one
+///
+/// The collapsed component is followed by `symbol` and the length of the run.
+/// With a `symbol` of `×`, `src/main/main/main/java` becomes `src/main×3/java`.
+fn collapse_repeated_segments(dir_string: &str, symbol: &str) -> String {
+    let mut components: Vec<(&str, usize)> = Vec::new();
+
+    for component in dir_string.split('/') {
+        match components.last_mut() {
+            Some((last, count)) if !component.is_empty() && *last == component => *count += 1,
+            _ => components.push((component, 1)),
+        }
+    }
+
+    components
+        .into_iter()
+        .map(|(component, count)| match count {
+            1 => component.to_string(),
+            _ => format!("{}{}{}", component, symbol, count),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 /// Takes part before contracted path and replaces it with fish style path
 ///
 /// Will take the first letter of each directory before the contracted path and
@@ -165,6 +220,16 @@ fn to_fish_style(pwd_dir_length: usize, dir_string: String, truncated_dir_string
 mod tests {
     use super::*;
 
+    #[test]
+    fn in_home_directory() {
+        let home = Path::new("/home/astronaut");
+
+        assert!(is_in_home(Path::new("/home/astronaut/schematics"), home));
+        assert!(is_in_home(Path::new("/home/astronaut"), home));
+        assert!(!is_in_home(Path::new("/etc"), home));
+        assert!(!is_in_home(Path::new("/home/astronautics"), home));
+    }
+
     #[test]
     fn contract_home_directory() {
         let full_path = Path::new("/Users/astronaut/schematics/rocket");
@@ -266,4 +331,18 @@ mod tests {
         let output = to_fish_style(1, path.to_string(), "目录");
         assert_eq!(output, "~/s/t/目/a̐/");
     }
+
+    #[test]
+    fn collapse_repeated_path_segments() {
+        let path = "~/src/main/main/main/java/com/example";
+        let output = collapse_repeated_segments(path, "×");
+        assert_eq!(output, "~/src/main×3/java/com/example");
+    }
+
+    #[test]
+    fn collapse_repeated_segments_leaves_distinct_paths() {
+        let path = "/absolute/Path/not/in_a/repo/src/main/src";
+        let output = collapse_repeated_segments(path, "×");
+        assert_eq!(output, path);
+    }
 }
@@ -36,7 +36,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             match current_context {
                 serde_json::Value::String(ctx) => {
                     module.set_style(config.style);
-                    module.create_segment("symbol", &config.symbol);
+                    module.create_segment(
+                        "symbol",
+                        context.symbol_style().select(
+                            &config.symbol,
+                            &config.unicode_symbol,
+                            &config.ascii_symbol,
+                        ),
+                    );
                     module.create_segment("context", &config.context.with_value(&ctx));
                     Some(module)
                 }
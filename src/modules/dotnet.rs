@@ -25,6 +25,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         .try_begin_scan()?
         .set_files(&[GLOBAL_JSON_FILE, PROJECT_JSON_FILE])
         .set_extensions(&["sln", "csproj", "fsproj", "xproj"])
+        .set_extensions_case_insensitive(true)
         .is_match();
 
     if !is_dotnet_project {
@@ -47,7 +48,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     };
 
     module.set_style(config.style);
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     module.create_segment("version", &config.version.with_value(&version.0));
 
     Some(module)
@@ -0,0 +1,90 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::earthly::EarthlyConfig;
+use crate::utils;
+
+/// Creates a module with the current Earthly version
+///
+/// Will display the Earthly version if the current directory contains an `Earthfile`
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_earthly_project = context
+        .try_begin_scan()?
+        .set_files(&["Earthfile"])
+        .is_match();
+
+    if !is_earthly_project {
+        return None;
+    }
+
+    let earthly_version = utils::exec_cmd("earthly", &["--version"])?.stdout;
+    let formatted_version = parse_earthly_version(&earthly_version)?;
+
+    let mut module = context.new_module("earthly");
+    let config: EarthlyConfig = EarthlyConfig::try_load(module.config);
+    module.set_style(config.style);
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    module.create_segment("version", &SegmentConfig::new(&formatted_version));
+
+    Some(module)
+}
+
+/// Extract the version from `earthly --version`, e.g.
+/// `earthly version v0.8.4 8e1e4b0c linux/amd64; Ubuntu 22.04`
+fn parse_earthly_version(earthly_version: &str) -> Option<String> {
+    let version = earthly_version
+        .split_whitespace()
+        .skip_while(|word| *word != "version")
+        .nth(1)?;
+
+    match version.starts_with('v') {
+        true => Some(version.to_string()),
+        false => Some(format!("v{}", version)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::File;
+    use std::io;
+
+    #[test]
+    fn test_parse_earthly_version() {
+        assert_eq!(
+            parse_earthly_version("earthly version v0.8.4 8e1e4b0c linux/amd64; Ubuntu 22.04\n"),
+            Some(String::from("v0.8.4"))
+        );
+        assert_eq!(
+            parse_earthly_version("earthly version 0.6.30 abcdef darwin/arm64"),
+            Some(String::from("v0.6.30"))
+        );
+        assert_eq!(parse_earthly_version("command not found"), None);
+    }
+
+    #[test]
+    fn folder_without_earthfile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module("earthly", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_earthfile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Earthfile"))?.sync_all()?;
+        let actual = render_module("earthly", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🌍 v0.8.4")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -30,7 +30,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config: ElmConfig = ElmConfig::try_load(module.config);
     module.set_style(config.style);
 
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     module.create_segment("version", &SegmentConfig::new(&formatted_version));
 
     Some(module)
@@ -4,6 +4,7 @@ use super::{Context, Module, SegmentConfig};
 
 use crate::config::RootModuleConfig;
 use crate::configs::env_var::EnvVarConfig;
+use crate::utils;
 
 /// Creates a module with the value of the chosen environment variable
 ///
@@ -11,11 +12,14 @@ use crate::configs::env_var::EnvVarConfig;
 ///     - env_var.disabled is absent or false
 ///     - env_var.variable is defined
 ///     - a variable named as the value of env_var.variable is defined
+///
+/// If env_var.decode_hex is true, the variable holds hex-encoded text, which is
+/// decoded before it is displayed.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("env_var");
     let config: EnvVarConfig = EnvVarConfig::try_load(module.config);
 
-    let env_value = get_env_value(config.variable?, config.default)?;
+    let env_value = get_env_value(config.variable?, config.default, config.decode_hex)?;
 
     module.set_style(config.style);
     module.get_prefix().set_value("with ");
@@ -31,9 +35,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_env_value(name: &str, default: Option<&str>) -> Option<String> {
+fn get_env_value(name: &str, default: Option<&str>, decode_hex: bool) -> Option<String> {
     match env::var_os(name) {
         Some(os_value) => match os_value.into_string() {
+            Ok(value) if decode_hex => String::from_utf8(utils::decode_from_hex(&value)?).ok(),
             Ok(value) => Some(value),
             Err(_error) => None,
         },
@@ -1,12 +1,15 @@
 use unicode_segmentation::UnicodeSegmentation;
 
-use super::{Context, Module, RootModuleConfig};
+use std::collections::HashMap;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
 
 use crate::configs::git_branch::GitBranchConfig;
 
 /// Creates a module with the Git branch in the current directory
 ///
-/// Will display the branch name if the current directory is a git repo
+/// Will display the branch name if the current directory is a git repo, followed
+/// by the `dirty` symbol if one is configured and the work tree has changes
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("git_branch");
     let config = GitBranchConfig::try_load(module.config);
@@ -15,7 +18,6 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.get_prefix().set_value("on ");
 
     let truncation_symbol = get_graphemes(config.truncation_symbol, 1);
-    module.create_segment("symbol", &config.symbol);
 
     // TODO: Once error handling is implemented, warn the user if their config
     // truncation length is nonsensical
@@ -29,8 +31,20 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         config.truncation_length as usize
     };
 
-    let repo = context.get_repo().ok()?;
-    let branch_name = repo.branch.as_ref()?;
+    let branch_name = get_branch_name(context)?;
+
+    match get_branch_type_symbol(&branch_name, &config.branch_type_symbols) {
+        Some(symbol) => module.create_segment("symbol", &SegmentConfig::new(symbol)),
+        None => module.create_segment(
+            "symbol",
+            context.symbol_style().select(
+                &config.symbol,
+                &config.unicode_symbol,
+                &config.ascii_symbol,
+            ),
+        ),
+    };
+
     let truncated_graphemes = get_graphemes(&branch_name, len);
     // The truncation symbol should only be added if we truncated
     let truncated_and_symbol = if len < graphemes_len(&branch_name) {
@@ -44,9 +58,47 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         &config.branch_name.with_value(&truncated_and_symbol),
     );
 
+    // Only look at the work tree if there is a symbol to show for it
+    if !config.dirty.value.is_empty() {
+        let repo = context.get_repo().ok()?;
+        if repo.is_dirty() == Some(true) {
+            module.create_segment("dirty", &config.dirty);
+        }
+    }
+
     Some(module)
 }
 
+/// The checked-out git branch, or for a Jujutsu workspace, which never has one,
+/// the bookmark or change id of the working-copy commit.
+fn get_branch_name(context: &Context) -> Option<String> {
+    let repo = context.get_repo().ok()?;
+
+    match repo.branch.as_deref() {
+        Some(branch) if branch != "HEAD" => Some(branch.to_string()),
+        branch => match context.get_jj_repo() {
+            Some(jj_repo) => jj_repo
+                .bookmark
+                .clone()
+                .or_else(|| jj_repo.change_id.clone()),
+            None => branch.map(String::from),
+        },
+    }
+}
+
+/// The symbol mapped to the longest prefix in `branch_type_symbols` that
+/// `branch_name` starts with, e.g. the symbol for `feature/` on `feature/login`
+fn get_branch_type_symbol<'a>(
+    branch_name: &str,
+    branch_type_symbols: &HashMap<String, &'a str>,
+) -> Option<&'a str> {
+    branch_type_symbols
+        .iter()
+        .filter(|(prefix, _)| branch_name.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, symbol)| *symbol)
+}
+
 fn get_graphemes(text: &str, length: usize) -> String {
     UnicodeSegmentation::graphemes(text, true)
         .take(length)
@@ -57,3 +109,24 @@ fn get_graphemes(text: &str, length: usize) -> String {
 fn graphemes_len(text: &str) -> usize {
     UnicodeSegmentation::graphemes(&text[..], true).count()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_branch_type_symbol() {
+        let mut symbols = HashMap::new();
+        symbols.insert(String::from("feature/"), "✨ ");
+        symbols.insert(String::from("hotfix/"), "🚑 ");
+        symbols.insert(String::from("hotfix/security-"), "🔒 ");
+
+        assert_eq!(get_branch_type_symbol("feature/x", &symbols), Some("✨ "));
+        assert_eq!(get_branch_type_symbol("hotfix/y", &symbols), Some("🚑 "));
+        assert_eq!(
+            get_branch_type_symbol("hotfix/security-z", &symbols),
+            Some("🔒 ")
+        );
+        assert_eq!(get_branch_type_symbol("master", &symbols), None);
+    }
+}
@@ -1,11 +1,13 @@
-use super::{Context, Module, RootModuleConfig};
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use git2::Repository;
 
 use crate::configs::git_commit::GitCommitConfig;
+use crate::utils::encode_to_hex;
 
 /// Creates a module with the Git commit in the current directory
 ///
-/// Will display the commit hash if the current directory is a git repo
+/// Will display the commit hash if the current directory is a git repo, followed
+/// by the tag pointing at it if `tag_disabled` is false
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("git_commit");
     let config = GitCommitConfig::try_load(module.config);
@@ -32,25 +34,66 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let git_head = git_repo.head().ok()?;
     let head_commit = git_head.peel_to_commit().ok()?;
     let commit_oid = head_commit.id();
-    module.create_segment(
-        "hash",
-        &config.hash.with_value(&id_to_hex_abbrev(
-            commit_oid.as_bytes(),
-            config.commit_hash_length,
-        )),
-    );
+    let hash = match config.hash_truncation {
+        "middle" => truncate_hash_middle(&commit_oid.to_string(), config.commit_hash_length),
+        truncation => {
+            if truncation != "prefix" {
+                log::warn!(
+                    "\"hash_truncation\" should be \"prefix\" or \"middle\", found {}",
+                    truncation
+                );
+            }
+            id_to_hex_abbrev(commit_oid.as_bytes(), config.commit_hash_length)
+        }
+    };
+    module.create_segment("hash", &config.hash.with_value(&hash));
+
+    if !config.tag_disabled {
+        if let Some(tag) = repo.tag_at_head(true) {
+            module.create_segment(
+                "tag",
+                &SegmentConfig::new(&format!("{}{}", config.tag_symbol, tag)),
+            );
+        }
+    }
 
     Some(module)
 }
 
 /// len specifies length of hex encoded string
 pub fn id_to_hex_abbrev(bytes: &[u8], len: usize) -> String {
-    bytes
-        .iter()
-        .map(|b| format!("{:02x}", b))
-        .collect::<Vec<String>>()
-        .join("")
-        .chars()
-        .take(len)
-        .collect()
+    encode_to_hex(bytes).chars().take(len).collect()
+}
+
+/// Abbreviate `hash` to `len / 2` characters from each end, joined by an ellipsis,
+/// e.g. `abc1…9def` for a length of 8
+fn truncate_hash_middle(hash: &str, len: usize) -> String {
+    let half = len / 2;
+    if half == 0 || half * 2 >= hash.len() {
+        return hash.chars().take(len).collect();
+    }
+
+    format!("{}…{}", &hash[..half], &hash[hash.len() - half..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "abc1234567890def1234567890abcdef12349def";
+
+    #[test]
+    fn test_prefix_truncation() {
+        let bytes = git2::Oid::from_str(HASH).unwrap();
+        assert_eq!(id_to_hex_abbrev(bytes.as_bytes(), 7), "abc1234");
+        assert_eq!(id_to_hex_abbrev(bytes.as_bytes(), 40), HASH);
+    }
+
+    #[test]
+    fn test_middle_truncation() {
+        assert_eq!(truncate_hash_middle(HASH, 8), "abc1…9def");
+        assert_eq!(truncate_hash_middle(HASH, 9), "abc1…9def");
+        assert_eq!(truncate_hash_middle(HASH, 40), HASH);
+        assert_eq!(truncate_hash_middle(HASH, 1), "a");
+    }
 }
@@ -0,0 +1,59 @@
+use super::{Context, Module, RootModuleConfig};
+use git2::Repository;
+use std::time::SystemTime;
+
+use super::cmd_duration::render_time;
+use crate::config::SegmentConfig;
+use crate::configs::git_fetch::GitFetchConfig;
+
+/// Creates a module showing how long ago the current repo was last fetched
+///
+/// The age is taken from the modification time of `FETCH_HEAD`, so the module
+/// is hidden in repos that have never been fetched.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_fetch");
+    let config = GitFetchConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let repo = context.get_repo().ok()?;
+    let repo_root = repo.root.as_ref()?;
+    let git_repo = Repository::open(repo_root).ok()?;
+
+    let fetch_head = git_repo.path().join("FETCH_HEAD");
+    let fetched_at = fetch_head.metadata().ok()?.modified().ok()?;
+    let age_secs = SystemTime::now()
+        .duration_since(fetched_at)
+        .map(|age| age.as_secs())
+        .unwrap_or(0);
+
+    if age_secs < config.min_age {
+        return None;
+    }
+
+    let style = if age_secs >= config.stale_age {
+        config.stale_style
+    } else {
+        config.style
+    };
+
+    module.set_style(style);
+    module
+        .get_prefix()
+        .set_value(config.prefix)
+        .set_style(style);
+    module
+        .get_suffix()
+        .set_value(config.suffix)
+        .set_style(style);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "age",
+        &SegmentConfig::new(&render_time(u128::from(age_secs) * 1000, false)),
+    );
+
+    Some(module)
+}
@@ -0,0 +1,94 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::git_metrics::GitMetricsConfig;
+use crate::utils;
+
+/// Creates a module with the number of lines added and deleted in the current repo
+///
+/// The counts compare the working tree and index to HEAD. They are computed with
+/// libgit2, unless `use_git_executable` is set, in which case `git diff --shortstat`
+/// is run instead.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_metrics");
+    let config = GitMetricsConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let repo = context.get_repo().ok()?;
+    let (added, deleted) = if config.use_git_executable {
+        let repo_root = repo.root.as_ref()?.to_str()?;
+        let output = utils::exec_cmd("git", &["-C", repo_root, "diff", "HEAD", "--shortstat"])?;
+        parse_shortstat(&output.stdout)
+    } else {
+        repo.diff_line_counts()?
+    };
+
+    let show_added = added > 0 || !config.only_nonzero_diffs;
+    let show_deleted = deleted > 0 || !config.only_nonzero_diffs;
+    if !show_added && !show_deleted {
+        return None;
+    }
+
+    module.get_prefix().set_value(config.prefix);
+    module.get_suffix().set_value(config.suffix);
+
+    if show_added {
+        module.create_segment(
+            "added",
+            &config
+                .added
+                .with_value(&format!("{}{}", config.added.value, added)),
+        );
+    }
+    if show_added && show_deleted {
+        module.create_segment("separator", &config.separator);
+    }
+    if show_deleted {
+        module.create_segment(
+            "deleted",
+            &config
+                .deleted
+                .with_value(&format!("{}{}", config.deleted.value, deleted)),
+        );
+    }
+
+    Some(module)
+}
+
+/// Read the insertions and deletions from the summary printed by `git diff --shortstat`,
+/// e.g. ` 2 files changed, 5 insertions(+), 1 deletion(-)`
+fn parse_shortstat(shortstat: &str) -> (usize, usize) {
+    let mut added = 0;
+    let mut deleted = 0;
+    for part in shortstat.trim().split(", ") {
+        let mut words = part.split_whitespace();
+        let count = words.next().and_then(|count| count.parse().ok());
+        match (count, words.next()) {
+            (Some(count), Some(kind)) if kind.starts_with("insertion") => added = count,
+            (Some(count), Some(kind)) if kind.starts_with("deletion") => deleted = count,
+            _ => (),
+        }
+    }
+    (added, deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shortstat() {
+        let both = " 2 files changed, 5 insertions(+), 1 deletion(-)\n";
+        assert_eq!(parse_shortstat(both), (5, 1));
+
+        let added = " 1 file changed, 1 insertion(+)\n";
+        assert_eq!(parse_shortstat(added), (1, 0));
+
+        let deleted = " 1 file changed, 3 deletions(-)\n";
+        assert_eq!(parse_shortstat(deleted), (0, 3));
+
+        assert_eq!(parse_shortstat(""), (0, 0));
+    }
+}
@@ -1,8 +1,9 @@
 use git2::RepositoryState;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use crate::configs::git_state::GitStateConfig;
+use crate::context::Repo;
 
 /// Creates a module with the state of the git repository at the current directory
 ///
@@ -17,10 +18,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.get_suffix().set_value(") ");
 
     let repo = context.get_repo().ok()?;
-    let repo_root = repo.root.as_ref()?;
     let repo_state = repo.state?;
+    let show_merge_source = config.show_merge_source;
 
-    let state_description = get_state_description(repo_state, repo_root, config);
+    let state_description = get_state_description(repo_state, repo, config);
 
     let label = match &state_description {
         StateDescription::Label(label) => label,
@@ -32,6 +33,15 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.create_segment(label.name, &label.segment);
 
+    if repo_state == RepositoryState::Merge && show_merge_source {
+        if let Some(merge_source) = repo.root.as_ref().and_then(|root| get_merge_source(root)) {
+            module.create_segment(
+                "merge_source",
+                &SegmentConfig::new(&format!(" {}", merge_source)),
+            );
+        }
+    }
+
     if let StateDescription::LabelAndProgress(_, progress) = &state_description {
         module.create_segment(
             "progress_current",
@@ -52,7 +62,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 /// During a git operation it will show: REBASING, BISECTING, MERGING, etc.
 fn get_state_description<'a>(
     state: RepositoryState,
-    root: &'a std::path::PathBuf,
+    repo: &'a Repo,
     config: GitStateConfig<'a>,
 ) -> StateDescription<'a> {
     match state {
@@ -77,57 +87,53 @@ fn get_state_description<'a>(
         RepositoryState::ApplyMailboxOrRebase => {
             StateDescription::Label(StateLabel::new("am_or_rebase", config.am_or_rebase))
         }
-        RepositoryState::Rebase => describe_rebase(root, config.rebase),
-        RepositoryState::RebaseInteractive => describe_rebase(root, config.rebase),
-        RepositoryState::RebaseMerge => describe_rebase(root, config.rebase),
+        RepositoryState::Rebase => describe_rebase(repo, config.rebase),
+        RepositoryState::RebaseInteractive => describe_rebase(repo, config.rebase),
+        RepositoryState::RebaseMerge => describe_rebase(repo, config.rebase),
     }
 }
 
-fn describe_rebase<'a>(
-    root: &'a PathBuf,
-    rebase_config: SegmentConfig<'a>,
-) -> StateDescription<'a> {
-    /*
-     *  Sadly, libgit2 seems to have some issues with reading the state of
-     *  interactive rebases. So, instead, we'll poke a few of the .git files
-     *  ourselves. This might be worth re-visiting this in the future...
-     *
-     *  The following is based heavily on: https://github.com/magicmonty/bash-git-prompt
-     */
-
-    let dot_git = root.join(".git");
-
-    let has_path = |relative_path: &str| {
-        let path = dot_git.join(Path::new(relative_path));
-        path.exists()
-    };
-
-    let file_to_usize = |relative_path: &str| {
-        let path = dot_git.join(Path::new(relative_path));
-        let contents = crate::utils::read_file(path).ok()?;
-        let quantity = contents.trim().parse::<usize>().ok()?;
-        Some(quantity)
-    };
-
-    let paths_to_progress = |current_path: &str, total_path: &str| {
-        let current = file_to_usize(current_path)?;
-        let total = file_to_usize(total_path)?;
-        Some(StateProgress { current, total })
-    };
+fn describe_rebase<'a>(repo: &'a Repo, rebase_config: SegmentConfig<'a>) -> StateDescription<'a> {
+    let label = StateLabel::new("rebase", rebase_config);
 
-    let progress = if has_path("rebase-merge") {
-        paths_to_progress("rebase-merge/msgnum", "rebase-merge/end")
-    } else if has_path("rebase-apply") {
-        paths_to_progress("rebase-apply/next", "rebase-apply/last")
-    } else {
-        None
-    };
+    match repo.rebase_progress() {
+        None => StateDescription::Label(label),
+        Some((current, total)) => {
+            StateDescription::LabelAndProgress(label, StateProgress { current, total })
+        }
+    }
+}
 
-    match progress {
-        None => StateDescription::Label(StateLabel::new("rebase", rebase_config)),
-        Some(progress) => {
-            StateDescription::LabelAndProgress(StateLabel::new("rebase", rebase_config), progress)
+/// Describes what is being merged, based on `.git/MERGE_HEAD` and `.git/MERGE_MSG`.
+///
+/// A single merge head is named after the branch or commit quoted in the merge
+/// message, falling back to its abbreviated hash. Octopus merges show the number
+/// of heads instead.
+fn get_merge_source(root: &Path) -> Option<String> {
+    let dot_git = root.join(".git");
+    let merge_head = crate::utils::read_file(dot_git.join("MERGE_HEAD")).ok()?;
+    let heads: Vec<&str> = merge_head
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    match heads.as_slice() {
+        [] => None,
+        [head] => {
+            let merge_msg = crate::utils::read_file(dot_git.join("MERGE_MSG")).unwrap_or_default();
+            let source = merge_msg
+                .lines()
+                .next()
+                .and_then(|line| line.split('\'').nth(1))
+                .filter(|name| !name.is_empty());
+
+            match source {
+                Some(source) => Some(source.to_string()),
+                None => Some(head.chars().take(7).collect()),
+            }
         }
+        heads => Some(format!("{} heads", heads.len())),
     }
 }
 
@@ -152,3 +158,72 @@ impl<'a> StateLabel<'a> {
         Self { name, segment }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io;
+
+    fn write_git_file(root: &Path, name: &str, contents: &str) -> io::Result<()> {
+        fs::create_dir_all(root.join(".git"))?;
+        fs::write(root.join(".git").join(name), contents)
+    }
+
+    #[test]
+    fn merge_source_from_merge_msg() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_git_file(
+            dir.path(),
+            "MERGE_HEAD",
+            "6d2c2f1a9d3e0f6f1f2a3c4b5d6e7f8091a2b3c4\n",
+        )?;
+        write_git_file(
+            dir.path(),
+            "MERGE_MSG",
+            "Merge branch 'other-branch'\n\n# Conflicts:\n#\tthe_file\n",
+        )?;
+
+        assert_eq!(
+            get_merge_source(dir.path()),
+            Some(String::from("other-branch"))
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn merge_source_falls_back_to_short_hash() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_git_file(
+            dir.path(),
+            "MERGE_HEAD",
+            "6d2c2f1a9d3e0f6f1f2a3c4b5d6e7f8091a2b3c4\n",
+        )?;
+
+        assert_eq!(get_merge_source(dir.path()), Some(String::from("6d2c2f1")));
+        dir.close()
+    }
+
+    #[test]
+    fn merge_source_octopus() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        write_git_file(
+            dir.path(),
+            "MERGE_HEAD",
+            "6d2c2f1a9d3e0f6f1f2a3c4b5d6e7f8091a2b3c4\n\
+             0a1b2c3d4e5f60718293a4b5c6d7e8f901234567\n\
+             89abcdef0123456789abcdef0123456789abcdef\n",
+        )?;
+        write_git_file(dir.path(), "MERGE_MSG", "Merge branches 'a', 'b' and 'c'\n")?;
+
+        assert_eq!(get_merge_source(dir.path()), Some(String::from("3 heads")));
+        dir.close()
+    }
+
+    #[test]
+    fn merge_source_without_merge_head() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(get_merge_source(dir.path()), None);
+        dir.close()
+    }
+}
@@ -1,11 +1,16 @@
 use git2::{Repository, Status};
 
+use super::cmd_duration::render_time;
 use super::{Context, Module, RootModuleConfig};
 
 use crate::config::SegmentConfig;
 use crate::configs::git_status::{CountConfig, GitStatusConfig};
+use crate::context::Repo;
 use std::borrow::BorrowMut;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Creates a module with the Git branch in the current directory
 ///
@@ -15,15 +20,17 @@ use std::collections::HashMap;
 ///   - `⇡` – This branch is ahead of the branch being tracked
 ///   - `⇣` – This branch is behind of the branch being tracked
 ///   - `⇕` – This branch has diverged from the branch being tracked
+///   - `⊘` – The branch being tracked has been deleted from the remote
 ///   - `?` — There are untracked files in the working directory
 ///   - `$` — A stash exists for the local repository
 ///   - `!` — There are file modifications in the working directory
 ///   - `+` — A new file has been added to the staging area
 ///   - `»` — A renamed file has been added to the staging area
 ///   - `✘` — A file's deletion has been added to the staging area
+///
+/// Optionally, the number of TODO/FIXME markers in changed files can be shown.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let repo = context.get_repo().ok()?;
-    let branch_name = repo.branch.as_ref()?;
     let repo_root = repo.root.as_ref()?;
     let mut repository = Repository::open(repo_root).ok()?;
 
@@ -40,11 +47,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         .set_style(config.style);
     module.set_style(config.style);
 
-    let repo_status = get_repo_status(repository.borrow_mut());
+    let repo_status = get_repo_status(repository.borrow_mut(), repo, config.max_changed_files);
     log::debug!("Repo status: {:?}", repo_status);
 
-    let ahead_behind = get_ahead_behind(&repository, branch_name);
-    if ahead_behind == Ok((0, 0)) {
+    let ahead_behind = match config.compare_remote {
+        "" => repo.ahead_behind(),
+        remote => repo.ahead_behind_remote(remote),
+    };
+    if ahead_behind == Some((0, 0)) {
         log::trace!("No ahead/behind found");
     } else {
         log::debug!("Repo ahead/behind: {:?}", ahead_behind);
@@ -58,11 +68,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.conflicted,
             &config.conflicted,
             config.conflicted_count,
+            is_conflicted(repo_status.uncounted),
         );
     }
 
     // Add the ahead/behind segment
-    if let Ok((ahead, behind)) = ahead_behind {
+    if let Some((ahead, behind)) = ahead_behind {
         let add_ahead = |m: &mut Module<'a>| {
             create_segment_with_count(
                 m,
@@ -73,6 +84,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     enabled: config.show_sync_count,
                     style: None,
                 },
+                false,
             );
         };
 
@@ -86,6 +98,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                     enabled: config.show_sync_count,
                     style: None,
                 },
+                false,
             );
         };
 
@@ -107,6 +120,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         }
     }
 
+    // Add the upstream gone segment
+    if let Some(branch_name) = &repo.branch {
+        if is_upstream_gone(&repository, branch_name) {
+            module.create_segment("upstream_gone", &config.upstream_gone);
+        }
+    }
+
     // Add the stashed segment
     if let Ok(repo_status) = repo_status {
         create_segment_with_count(
@@ -115,9 +135,38 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.stashed,
             &config.stashed,
             config.stashed_count,
+            false,
         );
     }
 
+    // Add the latest stash's message and age
+    if config.show_stash_message || config.show_stash_age {
+        if let Some(stash) = repo.latest_stash() {
+            if config.show_stash_message {
+                module.create_segment(
+                    "stash_message",
+                    &config
+                        .stash_message
+                        .with_value(&format!("{}{}", config.stash_message.value, stash.message)),
+                );
+            }
+
+            if config.show_stash_age {
+                let age_secs = unix_now().saturating_sub(stash.time).max(0) as u128;
+                let age = match render_time(age_secs * 1000, false) {
+                    age if age.is_empty() => String::from("0s"),
+                    age => age,
+                };
+                module.create_segment(
+                    "stash_age",
+                    &config
+                        .stash_age
+                        .with_value(&format!("{}{}", config.stash_age.value, age)),
+                );
+            }
+        }
+    }
+
     // Add all remaining status segments
     if let Ok(repo_status) = repo_status {
         create_segment_with_count(
@@ -126,6 +175,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.deleted,
             &config.deleted,
             config.deleted_count,
+            is_deleted(repo_status.uncounted),
         );
 
         create_segment_with_count(
@@ -134,6 +184,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.renamed,
             &config.renamed,
             config.renamed_count,
+            is_renamed(repo_status.uncounted),
         );
 
         create_segment_with_count(
@@ -142,6 +193,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.modified,
             &config.modified,
             config.modified_count,
+            is_modified(repo_status.uncounted),
         );
 
         create_segment_with_count(
@@ -150,6 +202,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.staged,
             &config.staged,
             config.staged_count,
+            is_staged(repo_status.uncounted),
         );
 
         create_segment_with_count(
@@ -158,9 +211,27 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             repo_status.untracked,
             &config.untracked,
             config.untracked_count,
+            is_untracked(repo_status.uncounted),
         );
     }
 
+    if config.show_todo_count {
+        match count_todo_markers(&repository, repo_root, &config) {
+            Ok(todo_count) => create_segment_with_count(
+                &mut module,
+                "todo",
+                todo_count,
+                &config.todo,
+                CountConfig {
+                    enabled: true,
+                    style: None,
+                },
+                false,
+            ),
+            Err(e) => log::debug!("Unable to count TODO markers: {}", e),
+        }
+    }
+
     if module.is_empty() {
         return None;
     }
@@ -168,27 +239,48 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
 fn create_segment_with_count<'a>(
     module: &mut Module<'a>,
     name: &str,
     count: usize,
     config: &SegmentConfig<'a>,
     count_config: CountConfig,
+    truncated: bool,
 ) {
     if count > 0 {
         module.create_segment(name, &config);
 
         if count_config.enabled {
+            let count = match truncated {
+                true => format!("{}+", count),
+                false => count.to_string(),
+            };
             module.create_segment(
                 &format!("{}_count", name),
-                &SegmentConfig::new(&count.to_string()).with_style(count_config.style),
+                &SegmentConfig::new(&count).with_style(count_config.style),
             );
         }
     }
 }
 
 /// Gets the number of files in various git states (staged, modified, deleted, etc...)
-fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Error> {
+///
+/// If `max_changed_files` is non-zero, only that many changed files are counted,
+/// and the states of the files that weren't are kept in `uncounted`. This bounds
+/// the counting, but not git's status pass itself, which still has to walk the
+/// whole work tree.
+fn get_repo_status(
+    repository: &mut Repository,
+    repo: &Repo,
+    max_changed_files: usize,
+) -> Result<RepoStatus, git2::Error> {
     let mut status_options = git2::StatusOptions::new();
 
     match repository.config()?.get_entry("status.showUntrackedFiles") {
@@ -198,19 +290,12 @@ fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Erro
     status_options
         .renames_from_rewrites(true)
         .renames_head_to_index(true)
-        .renames_index_to_workdir(true)
-        .include_unmodified(true);
-
-    let statuses: Vec<Status> = repository
-        .statuses(Some(&mut status_options))?
-        .iter()
-        .map(|s| s.status())
-        .collect();
+        .renames_index_to_workdir(true);
 
-    if statuses.is_empty() {
-        return Err(git2::Error::from_str("Repo has no status"));
-    }
+    let status_list = repository.statuses(Some(&mut status_options))?;
 
+    let (statuses, uncounted) =
+        limit_changed(status_list.iter().map(|s| s.status()), max_changed_files);
     let statuses_count = count_statuses(statuses);
 
     let repo_status: RepoStatus = RepoStatus {
@@ -220,12 +305,30 @@ fn get_repo_status(repository: &mut Repository) -> Result<RepoStatus, git2::Erro
         modified: *statuses_count.get("modified").unwrap_or(&0),
         staged: *statuses_count.get("staged").unwrap_or(&0),
         untracked: *statuses_count.get("untracked").unwrap_or(&0),
-        stashed: stashed_count(repository)?,
+        // A stash that can't be read shouldn't hide the rest of the status
+        stashed: repo.stash_count().unwrap_or(0),
+        uncounted,
     };
 
     Ok(repo_status)
 }
 
+/// Collects the changed statuses, stopping once `limit` of them have been seen
+///
+/// A `limit` of `0` means no limit. The states of the changes past the limit are
+/// combined into the second value, so that only the counts they would have added
+/// to are marked as truncated.
+fn limit_changed<I: Iterator<Item = Status>>(statuses: I, limit: usize) -> (Vec<Status>, Status) {
+    let mut changed = statuses.filter(|status| *status != Status::CURRENT);
+    if limit == 0 {
+        return (changed.collect(), Status::empty());
+    }
+
+    let limited: Vec<Status> = changed.by_ref().take(limit).collect();
+    let uncounted = changed.fold(Status::empty(), |uncounted, status| uncounted | status);
+    (limited, uncounted)
+}
+
 fn count_statuses(statuses: Vec<Status>) -> HashMap<&'static str, usize> {
     let mut predicates: HashMap<&'static str, fn(git2::Status) -> bool> = HashMap::new();
     predicates.insert("conflicted", is_conflicted);
@@ -270,32 +373,74 @@ fn is_untracked(status: Status) -> bool {
     status.is_wt_new()
 }
 
-fn stashed_count(repository: &mut Repository) -> Result<usize, git2::Error> {
-    let mut count = 0;
-    repository.stash_foreach(|_, _, _| {
-        count += 1;
-        true
-    })?;
-    Result::Ok(count)
+/// Whether the current branch is configured to track an upstream branch which
+/// does not exist anymore, e.g. because it was deleted from the remote
+fn is_upstream_gone(repository: &Repository, branch_name: &str) -> bool {
+    let branch_ref = format!("refs/heads/{}", branch_name);
+    let upstream_name = match repository.branch_upstream_name(&branch_ref) {
+        Ok(name) => name,
+        // There is no upstream configured for the branch
+        Err(_) => return false,
+    };
+
+    match upstream_name.as_str() {
+        Some(upstream_name) => repository.find_reference(upstream_name).is_err(),
+        None => false,
+    }
 }
 
-/// Compares the current branch with the branch it is tracking to determine how
-/// far ahead or behind it is in relation
-fn get_ahead_behind(
+/// Counts the configured markers (e.g. TODO/FIXME) in the files that have been
+/// changed in the working directory or staging area
+///
+/// To bound the cost on large changesets, at most `todo_max_files` files are
+/// read, and files larger than `todo_max_file_size` bytes are skipped.
+fn count_todo_markers(
     repository: &Repository,
-    branch_name: &str,
-) -> Result<(usize, usize), git2::Error> {
-    let branch_object = repository.revparse_single(branch_name)?;
-    let tracking_branch_name = format!("{}@{{upstream}}", branch_name);
-    let tracking_object = repository.revparse_single(&tracking_branch_name)?;
+    repo_root: &Path,
+    config: &GitStatusConfig,
+) -> Result<usize, git2::Error> {
+    let mut status_options = git2::StatusOptions::new();
+    status_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true);
 
-    let branch_oid = branch_object.id();
-    let tracking_oid = tracking_object.id();
+    let statuses = repository.statuses(Some(&mut status_options))?;
 
-    repository.graph_ahead_behind(branch_oid, tracking_oid)
+    let count = statuses
+        .iter()
+        .filter(|entry| is_changed(entry.status()))
+        .filter_map(|entry| entry.path().map(|path| repo_root.join(path)))
+        .take(config.todo_max_files)
+        .filter(|path| {
+            fs::metadata(path)
+                .map(|meta| meta.is_file() && meta.len() <= config.todo_max_file_size)
+                .unwrap_or(false)
+        })
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|content| count_markers(&content, &config.todo_markers))
+        .sum();
+
+    Ok(count)
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+fn is_changed(status: Status) -> bool {
+    status.is_wt_new()
+        || status.is_wt_modified()
+        || status.is_wt_renamed()
+        || status.is_index_new()
+        || status.is_index_modified()
+        || status.is_index_renamed()
+}
+
+fn count_markers(content: &str, markers: &[&str]) -> usize {
+    markers
+        .iter()
+        .filter(|marker| !marker.is_empty())
+        .map(|marker| content.matches(marker).count())
+        .sum()
+}
+
+#[derive(Debug, Copy, Clone)]
 struct RepoStatus {
     conflicted: usize,
     deleted: usize,
@@ -304,4 +449,41 @@ struct RepoStatus {
     staged: usize,
     untracked: usize,
     stashed: usize,
+    uncounted: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_markers() {
+        let content = "// TODO: first\nfn main() {} // FIXME\n// TODO: second\n";
+        assert_eq!(count_markers(content, &["TODO", "FIXME"]), 3);
+        assert_eq!(count_markers(content, &["TODO"]), 2);
+        assert_eq!(count_markers(content, &["XXX", ""]), 0);
+    }
+
+    #[test]
+    fn test_limit_changed() {
+        let statuses = vec![
+            Status::WT_NEW,
+            Status::CURRENT,
+            Status::WT_MODIFIED,
+            Status::WT_NEW,
+        ];
+
+        let (limited, uncounted) = limit_changed(statuses.clone().into_iter(), 0);
+        assert_eq!(limited.len(), 3);
+        assert!(uncounted.is_empty());
+
+        let (limited, uncounted) = limit_changed(statuses.clone().into_iter(), 3);
+        assert_eq!(limited.len(), 3);
+        assert!(uncounted.is_empty());
+
+        let (limited, uncounted) = limit_changed(statuses.into_iter(), 2);
+        assert_eq!(limited, vec![Status::WT_NEW, Status::WT_MODIFIED]);
+        assert!(is_untracked(uncounted));
+        assert!(!is_modified(uncounted));
+    }
 }
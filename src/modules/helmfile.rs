@@ -0,0 +1,161 @@
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::helmfile::HelmfileConfig;
+use crate::utils;
+
+/// Creates a module with the current helm version and Helmfile environment
+///
+/// Will display if the current directory contains a `helmfile.yaml` file or a
+/// `helmfile.d` folder. The environment is read from `$HELMFILE_ENVIRONMENT`, or
+/// is `default` if `helmfile.yaml` declares a `default` environment.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_helmfile_project = context
+        .try_begin_scan()?
+        .set_files(&["helmfile.yaml"])
+        .set_folders(&["helmfile.d"])
+        .is_match();
+
+    if !is_helmfile_project {
+        return None;
+    }
+
+    let helm_version = utils::exec_cmd("helm", &["version", "--short", "--client"])?.stdout;
+    let formatted_version = parse_helm_version(&helm_version)?;
+
+    let mut module = context.new_module("helmfile");
+    let config: HelmfileConfig = HelmfileConfig::try_load(module.config);
+    module.set_style(config.style);
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    module.create_segment("version", &SegmentConfig::new(&formatted_version));
+
+    if config.show_environment {
+        let environment = context
+            .get_env("HELMFILE_ENVIRONMENT")
+            .filter(|environment| !environment.trim().is_empty())
+            .or_else(|| {
+                let content = utils::read_file(context.current_dir.join("helmfile.yaml")).ok()?;
+                parse_environments(&content)
+                    .into_iter()
+                    .find(|environment| environment == "default")
+            });
+        if let Some(environment) = environment {
+            module.create_segment(
+                "environment",
+                &SegmentConfig::new(&format!(" ({})", environment.trim())),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+/// Extract the version from `helm version --short --client`, e.g. `v3.12.0+gc9f554d`
+fn parse_helm_version(helm_version: &str) -> Option<String> {
+    let version = helm_version
+        .trim()
+        .trim_start_matches("Client: ")
+        .split('+')
+        .next()?;
+
+    match version {
+        "" => None,
+        version if version.starts_with('v') => Some(version.to_string()),
+        version => Some(format!("v{}", version)),
+    }
+}
+
+/// The names of the environments declared in a `helmfile.yaml`
+///
+/// Helmfiles are often templated, in which case they can't be parsed and no
+/// environments are returned.
+fn parse_environments(content: &str) -> Vec<String> {
+    let docs = match YamlLoader::load_from_str(content) {
+        Ok(docs) => docs,
+        Err(error) => {
+            log::debug!("Unable to parse helmfile.yaml: {}", error);
+            return Vec::new();
+        }
+    };
+
+    docs.iter()
+        .filter_map(|doc| doc["environments"].as_hash())
+        .flat_map(|environments| environments.keys())
+        .filter_map(|name| name.as_str().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    #[test]
+    fn test_parse_helm_version() {
+        assert_eq!(
+            parse_helm_version("v3.12.0+gc9f554d\n"),
+            Some(String::from("v3.12.0"))
+        );
+        assert_eq!(
+            parse_helm_version("Client: v2.16.1+gbbdfe5e"),
+            Some(String::from("v2.16.1"))
+        );
+        assert_eq!(parse_helm_version(""), None);
+    }
+
+    #[test]
+    fn test_parse_environments() {
+        let helmfile = "environments:\n  default:\n    values: []\n  production:\n    values: []\n---\nreleases: []\n";
+        assert_eq!(
+            parse_environments(helmfile),
+            vec![String::from("default"), String::from("production")]
+        );
+        assert!(parse_environments("releases: []\n").is_empty());
+    }
+
+    #[test]
+    fn folder_with_helmfile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("helmfile.yaml"))?.sync_all()?;
+        let actual = render_module("helmfile", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::White.bold().paint("⎈ v3.12.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_helmfile_d() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("helmfile.d"))?;
+        let actual = render_module("helmfile", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::White.bold().paint("⎈ v3.12.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_default_environment() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("helmfile.yaml"),
+            "environments:\n  default: {}\n  staging: {}\n",
+        )?;
+        let actual = render_module("helmfile", dir.path(), None);
+        let expected = Some(format!(
+            "via {} ",
+            Color::White.bold().paint("⎈ v3.12.0 (default)")
+        ));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
@@ -1,5 +1,3 @@
-use std::env;
-
 use super::{Context, Module, SegmentConfig};
 use std::ffi::OsString;
 
@@ -10,13 +8,13 @@ use crate::configs::hostname::HostnameConfig;
 ///
 /// Will display the hostname if all of the following criteria are met:
 ///     - hostname.disabled is absent or false
-///     - hostname.ssh_only is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`)
+///     - hostname.ssh_only is false OR the user is currently connected as an SSH session
+///     - hostname.baseline is empty OR differs from the hostname
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("hostname");
     let config: HostnameConfig = HostnameConfig::try_load(module.config);
 
-    let ssh_connection = env::var("SSH_CONNECTION").ok();
-    if config.ssh_only && ssh_connection.is_none() {
+    if config.ssh_only && !context.is_ssh_session() {
         return None;
     }
 
@@ -42,6 +40,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         host.as_ref()
     };
 
+    if !config.baseline.is_empty() && config.baseline == host {
+        return None;
+    }
+
     module.set_style(config.style);
     let hostname_stacked = format!("{}{}{}", config.prefix, host, config.suffix);
     module.create_segment("hostname", &SegmentConfig::new(&hostname_stacked));
@@ -28,7 +28,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.set_style(config.style);
 
             let formatted_version = format_java_version(java_version)?;
-            module.create_segment("symbol", &config.symbol);
+            module.create_segment(
+                "symbol",
+                context.symbol_style().select(
+                    &config.symbol,
+                    &config.unicode_symbol,
+                    &config.ascii_symbol,
+                ),
+            );
             module.create_segment("version", &SegmentConfig::new(&formatted_version));
 
             Some(module)
@@ -44,7 +51,7 @@ fn get_java_version() -> Option<String> {
     };
 
     let output = utils::exec_cmd(&java_command.as_str(), &["-Xinternalversion"])?;
-    Some(format!("{}{}", output.stdout, output.stderr))
+    Some(output.combined())
 }
 
 /// Extract the java version from `java_out`.
@@ -10,19 +10,17 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.set_style(config.style);
 
-    let props = &context.properties;
-    let num_of_jobs = props
-        .get("jobs")
-        .unwrap_or(&"0".into())
-        .trim()
-        .parse::<i64>()
-        .ok()?;
-    if num_of_jobs == 0 {
+    if !context.jobs_over(0) {
         return None;
     }
-    module.create_segment("symbol", &config.symbol);
-    if num_of_jobs > config.threshold {
-        module.create_segment("number", &SegmentConfig::new(&num_of_jobs.to_string()));
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    if context.jobs_over(config.threshold) {
+        module.create_segment("number", &SegmentConfig::new(&context.jobs().to_string()));
     }
     module.get_prefix().set_value("");
 
@@ -24,7 +24,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config: JuliaConfig = JuliaConfig::try_load(module.config);
 
     module.set_style(config.style);
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
 
     let formatted_version =
         format_julia_version(&utils::exec_cmd("julia", &["--version"])?.stdout.as_str())?;
@@ -42,6 +42,31 @@ fn parse_kubectl_file(filename: &path::PathBuf) -> Option<(String, String)> {
     get_kube_context(&contents)
 }
 
+/// Read a namespace override from `filename`, e.g. a state file written by a tool
+/// like kubens. A leading `~` is replaced with the home directory.
+fn read_namespace_file(filename: &str) -> Option<String> {
+    let path = match filename.strip_prefix("~/") {
+        Some(relative) => dirs::home_dir()?.join(relative),
+        None => path::PathBuf::from(filename),
+    };
+    utils::read_file(path).ok()
+}
+
+/// Pick the namespace to display: `$KUBE_NAMESPACE` wins over the override file,
+/// which wins over the namespace of the kubeconfig context
+fn resolve_namespace(
+    env_namespace: Option<String>,
+    file_namespace: Option<String>,
+    kube_namespace: String,
+) -> String {
+    env_namespace
+        .into_iter()
+        .chain(file_namespace)
+        .map(|namespace| namespace.trim().to_string())
+        .find(|namespace| !namespace.is_empty())
+        .unwrap_or(kube_namespace)
+}
+
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let kube_cfg = match env::var("KUBECONFIG") {
         Ok(paths) => env::split_paths(&paths)
@@ -66,7 +91,21 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.set_style(config.style);
             module.get_prefix().set_value(KUBERNETES_PREFIX);
 
-            module.create_segment("symbol", &config.symbol);
+            module.create_segment(
+                "symbol",
+                context.symbol_style().select(
+                    &config.symbol,
+                    &config.unicode_symbol,
+                    &config.ascii_symbol,
+                ),
+            );
+
+            let file_namespace = match config.namespace_file {
+                "" => None,
+                filename => read_namespace_file(filename),
+            };
+            let kube_ns =
+                resolve_namespace(context.get_env("KUBE_NAMESPACE"), file_namespace, kube_ns);
 
             let displayed_context = match config.context_aliases.get(&kube_ctx) {
                 None => &kube_ctx,
@@ -90,6 +129,27 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn namespace_precedence() {
+        let kube_ns = String::from("from-kubeconfig");
+        let env_ns = Some(String::from("from-env"));
+        let file_ns = Some(String::from("from-file\n"));
+
+        assert_eq!(
+            resolve_namespace(env_ns.clone(), file_ns.clone(), kube_ns.clone()),
+            "from-env"
+        );
+        assert_eq!(
+            resolve_namespace(None, file_ns, kube_ns.clone()),
+            "from-file"
+        );
+        assert_eq!(
+            resolve_namespace(Some(String::from("")), None, kube_ns.clone()),
+            "from-kubeconfig"
+        );
+        assert_eq!(resolve_namespace(None, None, kube_ns), "from-kubeconfig");
+    }
+
     #[test]
     fn parse_empty_config() {
         let input = "";
@@ -0,0 +1,63 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::locale::LocaleConfig;
+
+/// Creates a module with the active locale
+///
+/// Will display the locale from `$LC_ALL`, or `$LANG` if that isn't set, unless
+/// it is the configured `default_locale`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let locale = ["LC_ALL", "LANG"]
+        .iter()
+        .filter_map(|key| context.get_env(key))
+        .find(|value| !value.trim().is_empty())?;
+    let locale = locale.trim();
+
+    let mut module = context.new_module("locale");
+    let config: LocaleConfig = LocaleConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let short_locale = shorten_locale(locale);
+    if locale == config.default_locale || short_locale == config.default_locale {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+
+    let displayed = if config.show_full {
+        locale
+    } else {
+        short_locale
+    };
+    module.create_segment("locale", &SegmentConfig::new(displayed));
+
+    Some(module)
+}
+
+/// Drop the codeset and modifier of a locale, e.g. `en_US.UTF-8` becomes `en_US`
+/// and `de_DE@euro` becomes `de_DE`.
+fn shorten_locale(locale: &str) -> &str {
+    locale.split(['.', '@']).next().unwrap_or(locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shorten_locale() {
+        assert_eq!(shorten_locale("en_US.UTF-8"), "en_US");
+        assert_eq!(shorten_locale("de_DE@euro"), "de_DE");
+        assert_eq!(shorten_locale("sr_RS.UTF-8@latin"), "sr_RS");
+        assert_eq!(shorten_locale("C"), "C");
+    }
+}
@@ -29,7 +29,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     module.set_style(config.style);
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
 
     let system = sysinfo::System::new_with_specifics(RefreshKind::new().with_memory());
 
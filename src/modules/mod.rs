@@ -1,23 +1,32 @@
 // While adding out new module add out module to src/module.rs ALL_MODULES const array also.
+mod android;
+mod app_env;
 mod aws;
+mod buf;
 mod character;
 mod cmd_duration;
 mod conda;
+mod container;
 mod crystal;
 pub(crate) mod custom;
+mod devbox;
 mod directory;
 mod docker_context;
 mod dotnet;
+mod earthly;
 mod elixir;
 mod elm;
 mod env_var;
 mod erlang;
 mod git_branch;
 mod git_commit;
+mod git_fetch;
+mod git_metrics;
 mod git_state;
 mod git_status;
 mod golang;
 mod haskell;
+mod helmfile;
 mod hg_branch;
 mod hostname;
 mod java;
@@ -25,19 +34,26 @@ mod jobs;
 mod julia;
 mod kubernetes;
 mod line_break;
+mod locale;
 mod memory_usage;
+mod nix_profile;
 mod nix_shell;
 mod nodejs;
 mod package;
 mod php;
+mod pid;
 mod python;
 mod ruby;
 mod rust;
 mod singularity;
+mod sops;
+mod systemd;
+mod task_runner;
 mod terraform;
 mod time;
 mod username;
 mod utils;
+mod zellij;
 
 #[cfg(feature = "battery")]
 mod battery;
@@ -50,25 +66,34 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
     match module {
         // Keep these ordered alphabetically.
         // Default ordering is handled in configs/mod.rs
+        "android" => android::module(context),
+        "app_env" => app_env::module(context),
         "aws" => aws::module(context),
         #[cfg(feature = "battery")]
         "battery" => battery::module(context),
+        "buf" => buf::module(context),
         "character" => character::module(context),
         "cmd_duration" => cmd_duration::module(context),
         "conda" => conda::module(context),
+        "container" => container::module(context),
+        "devbox" => devbox::module(context),
         "directory" => directory::module(context),
         "docker_context" => docker_context::module(context),
         "dotnet" => dotnet::module(context),
+        "earthly" => earthly::module(context),
         "elixir" => elixir::module(context),
         "elm" => elm::module(context),
         "erlang" => erlang::module(context),
         "env_var" => env_var::module(context),
         "git_branch" => git_branch::module(context),
         "git_commit" => git_commit::module(context),
+        "git_fetch" => git_fetch::module(context),
+        "git_metrics" => git_metrics::module(context),
         "git_state" => git_state::module(context),
         "git_status" => git_status::module(context),
         "golang" => golang::module(context),
         "haskell" => haskell::module(context),
+        "helmfile" => helmfile::module(context),
         "hg_branch" => hg_branch::module(context),
         "hostname" => hostname::module(context),
         "java" => java::module(context),
@@ -76,19 +101,26 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
         "julia" => julia::module(context),
         "kubernetes" => kubernetes::module(context),
         "line_break" => line_break::module(context),
+        "locale" => locale::module(context),
         "memory_usage" => memory_usage::module(context),
+        "nix_profile" => nix_profile::module(context),
         "nix_shell" => nix_shell::module(context),
         "nodejs" => nodejs::module(context),
         "package" => package::module(context),
         "php" => php::module(context),
+        "pid" => pid::module(context),
         "python" => python::module(context),
         "ruby" => ruby::module(context),
         "rust" => rust::module(context),
         "singularity" => singularity::module(context),
+        "sops" => sops::module(context),
+        "systemd" => systemd::module(context),
+        "task_runner" => task_runner::module(context),
         "terraform" => terraform::module(context),
         "time" => time::module(context),
         "crystal" => crystal::module(context),
         "username" => username::module(context),
+        "zellij" => zellij::module(context),
         _ => {
             eprintln!("Error: Unknown module {}. Use starship module --list to list out all supported modules.", module);
             None
@@ -98,25 +130,36 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
 
 pub fn description(module: &str) -> &'static str {
     match module {
+        "android" => "The Android SDK versions of the current project",
+        "app_env" => "The application environment, e.g. production or development",
         "aws" => "The current AWS region and profile",
         "battery" => "The current charge of the device's battery and its current charging status",
+        "buf" => "The current buf version and Protobuf module name",
         "character" => {
             "A character (usually an arrow) beside where the text is entered in your terminal"
         }
         "cmd_duration" => "How long the last command took to execute",
         "conda" => "The current conda environment, if $CONDA_DEFAULT_ENV is set",
+        "container" => "The container runtime starship is running in",
         "crystal" => "The currently installed version of Crystal",
+        "devbox" => "Whether a Devbox environment is active and how many packages it has",
         "directory" => "The current working directory",
         "docker_context" => "The current docker context",
         "dotnet" => "The relevant version of the .NET Core SDK for the current directory",
+        "earthly" => "The currently installed version of Earthly",
         "env_var" => "Displays the current value of a selected environment variable",
         "erlang" => "Current OTP version",
         "git_branch" => "The active branch of the repo in your current directory",
         "git_commit" => "The active commit of the repo in your current directory",
+        "git_fetch" => "How long ago the repo was last fetched",
+        "git_metrics" => {
+            "The number of lines added and deleted in the repo in your current directory"
+        }
         "git_state" => "The current git operation, and it's progress",
         "git_status" => "Symbol representing the state of the repo",
         "golang" => "The currently installed version of Golang",
         "haskell" => "The currently used version of Haskell",
+        "helmfile" => "The current helm version and Helmfile environment",
         "hg_branch" => "The active branch of the repo in your current directory",
         "hostname" => "The system hostname",
         "java" => "The currently installed version of Java",
@@ -124,17 +167,26 @@ pub fn description(module: &str) -> &'static str {
         "julia" => "The currently installed version of Julia",
         "kubernetes" => "The current Kubernetes context name and, if set, the namespace",
         "line_break" => "Separates the prompt into two lines",
+        "locale" => "The active locale, from LC_ALL or LANG",
         "memory_usage" => "Current system memory and swap usage",
+        "nix_profile" => "The generation of the active Nix profile",
         "nix_shell" => "The nix-shell environment",
         "nodejs" => "The currently installed version of NodeJS",
         "package" => "The package version of the current directory's project",
         "php" => "The currently installed version of PHP",
+        "pid" => "The process ID of the current shell",
         "python" => "The currently installed version of Python",
         "ruby" => "The currently installed version of Ruby",
         "rust" => "The currently installed version of Rust",
+        "sops" => {
+            "The SOPS configuration of the current directory and whether an age key is available"
+        }
+        "systemd" => "The systemd unit the shell is running in",
+        "task_runner" => "The task runner of the current project (go-task, just or make)",
         "terraform" => "The currently selected terraform workspace and version",
         "time" => "The current local time",
         "username" => "The active user's username",
+        "zellij" => "The active zellij session name",
         _ => "<no description>",
     }
 }
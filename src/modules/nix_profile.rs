@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::nix_profile::NixProfileConfig;
+
+/// Creates a module with the generation of the active Nix profile
+///
+/// Will display the generation if either of the following profiles exists:
+///     - `~/.nix-profile`
+///     - `~/.local/state/nix/profiles/profile`
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let home_dir = dirs::home_dir()?;
+    let generation = get_profile_generation(&home_dir.join(".nix-profile"))
+        .or_else(|| get_profile_generation(&home_dir.join(".local/state/nix/profiles/profile")))?;
+
+    let mut module = context.new_module("nix_profile");
+    let config: NixProfileConfig = NixProfileConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    module.create_segment("generation", &SegmentConfig::new(&generation.to_string()));
+
+    Some(module)
+}
+
+/// Follow the profile symlinks until one of them points at a `<name>-<n>-link`
+/// generation, returning `n`.
+///
+/// Nix lays a profile out as `profile -> profile-<n>-link -> /nix/store/...`,
+/// with `~/.nix-profile` usually being one more link in front of that chain.
+fn get_profile_generation(profile: &Path) -> Option<u64> {
+    let mut current = profile.to_path_buf();
+
+    // Guard against symlink loops; real profiles are at most a few links deep
+    for _ in 0..8 {
+        let target = fs::read_link(&current).ok()?;
+        let target = resolve_link(&current, target);
+
+        if let Some(generation) = parse_generation(&target) {
+            return Some(generation);
+        }
+        current = target;
+    }
+
+    None
+}
+
+/// Link targets may be relative to the directory containing the link.
+fn resolve_link(link: &Path, target: PathBuf) -> PathBuf {
+    if target.is_absolute() {
+        return target;
+    }
+    match link.parent() {
+        Some(parent) => parent.join(target),
+        None => target,
+    }
+}
+
+fn parse_generation(path: &Path) -> Option<u64> {
+    let file_name = path.file_name()?.to_str()?;
+    if !file_name.ends_with("-link") {
+        return None;
+    }
+    let without_suffix = file_name.trim_end_matches("-link");
+    let generation = without_suffix.rsplit('-').next()?;
+    generation.parse::<u64>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_parse_generation() {
+        assert_eq!(parse_generation(Path::new("profile-42-link")), Some(42));
+        assert_eq!(
+            parse_generation(Path::new(
+                "/nix/var/nix/profiles/per-user/bob/profile-7-link"
+            )),
+            Some(7)
+        );
+        assert_eq!(parse_generation(Path::new("profile")), None);
+        assert_eq!(parse_generation(Path::new("profile-x-link")), None);
+        assert_eq!(
+            parse_generation(Path::new("/nix/store/abc123-user-environment")),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_get_profile_generation() -> io::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempfile::tempdir()?;
+        let profiles = dir.path().join("profiles");
+        fs::create_dir(&profiles)?;
+        let store = dir.path().join("user-environment");
+        fs::create_dir(&store)?;
+
+        symlink(&store, profiles.join("profile-12-link"))?;
+        symlink("profile-12-link", profiles.join("profile"))?;
+        let nix_profile = dir.path().join(".nix-profile");
+        symlink(profiles.join("profile"), &nix_profile)?;
+
+        assert_eq!(get_profile_generation(&nix_profile), Some(12));
+        assert_eq!(get_profile_generation(&profiles.join("profile")), Some(12));
+        assert_eq!(get_profile_generation(&store), None);
+        assert_eq!(get_profile_generation(&dir.path().join("missing")), None);
+
+        dir.close()
+    }
+}
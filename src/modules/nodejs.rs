@@ -9,6 +9,8 @@ use crate::utils;
 ///     - Current directory contains a `.js` file
 ///     - Current directory contains a `package.json` or `.node-version` file
 ///     - Current directory contains a `node_modules` directory
+///
+/// A version pinned in `.tool-versions` or `mise.toml` is shown as is.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_js_project = context
         .try_begin_scan()?
@@ -21,7 +23,15 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let node_version = utils::exec_cmd("node", &["--version"])?.stdout;
+    // A version pinned for asdf or mise saves running `node`
+    let node_version = match context
+        .tool_version_pin("nodejs")
+        .or_else(|| context.tool_version_pin("node"))
+        .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+    {
+        Some(version) => format!("v{}", version),
+        None => utils::exec_cmd("node", &["--version"])?.stdout,
+    };
 
     let mut module = context.new_module("nodejs");
     let config: NodejsConfig = NodejsConfig::try_load(module.config);
@@ -29,7 +39,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_style(config.style);
 
     let formatted_version = node_version.trim();
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     module.create_segment("version", &SegmentConfig::new(formatted_version));
 
     Some(module)
@@ -95,4 +110,28 @@ mod tests {
         assert_eq!(expected, actual);
         dir.close()
     }
+
+    #[test]
+    fn folder_with_tool_versions_pin() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("package.json"))?.sync_all()?;
+        fs::write(dir.path().join(".tool-versions"), "nodejs 18.16.0\n")?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v18.16.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_system_pin() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("package.json"))?.sync_all()?;
+        fs::write(dir.path().join(".tool-versions"), "nodejs system\n")?;
+
+        let actual = render_module("nodejs", dir.path(), None);
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
 }
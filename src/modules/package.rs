@@ -21,7 +21,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.set_style(config.style);
             module.get_prefix().set_value("is ");
 
-            module.create_segment("symbol", &config.symbol);
+            module.create_segment(
+                "symbol",
+                context.symbol_style().select(
+                    &config.symbol,
+                    &config.unicode_symbol,
+                    &config.ascii_symbol,
+                ),
+            );
             module.create_segment("version", &SegmentConfig::new(&package_version));
 
             Some(module)
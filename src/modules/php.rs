@@ -35,7 +35,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.set_style(config.style);
 
             let formatted_version = format_php_version(&php_version)?;
-            module.create_segment("symbol", &config.symbol);
+            module.create_segment(
+                "symbol",
+                context.symbol_style().select(
+                    &config.symbol,
+                    &config.unicode_symbol,
+                    &config.ascii_symbol,
+                ),
+            );
             module.create_segment("version", &SegmentConfig::new(&formatted_version));
 
             Some(module)
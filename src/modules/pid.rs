@@ -0,0 +1,26 @@
+use super::{Context, Module};
+
+use crate::config::{RootModuleConfig, SegmentConfig};
+use crate::configs::pid::PidConfig;
+
+/// Creates a module with the process ID of the current shell
+///
+/// Will display the PID iff the shell passed it with `--pid`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let pid = context.shell_pid()?;
+
+    let mut module = context.new_module("pid");
+    let config: PidConfig = PidConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("pid", &SegmentConfig::new(&pid.to_string()));
+
+    Some(module)
+}
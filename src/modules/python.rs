@@ -1,5 +1,4 @@
 use std::env;
-use std::path::Path;
 
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use crate::configs::python::PythonConfig;
@@ -42,7 +41,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     module.set_style(config.style);
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
 
     if config.pyenv_version_name {
         let python_version = utils::exec_cmd("pyenv", &["version-name"])?.stdout;
@@ -54,7 +58,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         module.create_segment("version", &SegmentConfig::new(&formatted_version));
     };
 
-    if let Some(virtual_env) = get_python_virtual_env() {
+    if let Some(virtual_env) = context.virtualenv_prompt() {
         module.create_segment(
             "virtualenv",
             &SegmentConfig::new(&format!(" ({})", virtual_env)),
@@ -87,14 +91,6 @@ fn format_python_version(python_stdout: &str) -> String {
     )
 }
 
-fn get_python_virtual_env() -> Option<String> {
-    env::var("VIRTUAL_ENV").ok().and_then(|venv| {
-        Path::new(&venv)
-            .file_name()
-            .map(|filename| String::from(filename.to_str().unwrap_or("")))
-    })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,8 @@ use crate::utils;
 /// Will display the Ruby version if any of the following criteria are met:
 ///     - Current directory contains a `.rb` file
 ///     - Current directory contains a `Gemfile` or `.ruby-version` file
+///
+/// A version pinned in `.tool-versions` or `mise.toml` is shown as is.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_rb_project = context
         .try_begin_scan()?
@@ -19,14 +21,25 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let ruby_version = utils::exec_cmd("ruby", &["-v"])?.stdout;
-    let formatted_version = format_ruby_version(&ruby_version)?;
+    // A version pinned for asdf or mise saves running `ruby`
+    let formatted_version = match context
+        .tool_version_pin("ruby")
+        .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+    {
+        Some(version) => format!("v{}", version),
+        None => format_ruby_version(&utils::exec_cmd("ruby", &["-v"])?.stdout)?,
+    };
 
     let mut module = context.new_module("ruby");
     let config: RubyConfig = RubyConfig::try_load(module.config);
     module.set_style(config.style);
 
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     module.create_segment("version", &SegmentConfig::new(&formatted_version));
 
     Some(module)
@@ -92,6 +105,19 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn folder_with_mise_pin() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Gemfile"))?.sync_all()?;
+        std::fs::write(dir.path().join("mise.toml"), "[tools]\nruby = \"3.2.2\"\n")?;
+
+        let actual = render_module("ruby", dir.path(), None);
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("💎 v3.2.2")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
     #[test]
     fn folder_with_rb_file() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -1,16 +1,20 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 use std::{env, fs};
 
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::rust::RustConfig;
+use crate::utils;
 
 /// Creates a module with the current Rust version
 ///
 /// Will display the Rust version if any of the following criteria are met:
 ///     - Current directory contains a file with a `.rs` extension
 ///     - Current directory contains a `Cargo.toml` file
+///
+/// If a registry other than crates.io is configured as the default, it is shown
+/// after the version.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_rs_project = context
         .try_begin_scan()?
@@ -60,24 +64,69 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config = RustConfig::try_load(module.config);
     module.set_style(config.style);
 
-    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
     module.create_segment("version", &config.version.with_value(&module_version));
 
+    if let Some(registry) = get_cargo_registry(context.ancestors()) {
+        module.create_segment(
+            "registry",
+            &config.registry.with_value(&format!(" ({})", registry)),
+        );
+    }
+
     Some(module)
 }
 
+/// Determine the default cargo registry, returning `None` if it is crates.io
+///
+/// `$CARGO_REGISTRY_DEFAULT` takes precedence over `registry.default` in the cargo
+/// config files, which are looked up the same way cargo does: in `.cargo/` of the
+/// current directory and each of its parents, and finally in `$CARGO_HOME`.
+fn get_cargo_registry<'p>(ancestors: impl Iterator<Item = &'p Path>) -> Option<String> {
+    let registry = env::var("CARGO_REGISTRY_DEFAULT")
+        .ok()
+        .or_else(|| find_cargo_config_registry(ancestors))?;
+
+    if registry == "crates-io" {
+        return None;
+    }
+    Some(registry)
+}
+
+fn find_cargo_config_registry<'p>(ancestors: impl Iterator<Item = &'p Path>) -> Option<String> {
+    let cargo_home = env::var("CARGO_HOME")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")));
+
+    ancestors
+        .map(|dir| dir.join(".cargo"))
+        .chain(cargo_home)
+        .find_map(|cargo_dir| read_cargo_config_registry(&cargo_dir))
+}
+
+fn read_cargo_config_registry(cargo_dir: &Path) -> Option<String> {
+    ["config.toml", "config"].iter().find_map(|file_name| {
+        let content = fs::read_to_string(cargo_dir.join(file_name)).ok()?;
+        let config = content.parse::<toml::Value>().ok()?;
+        let registry = config.get("registry")?.get("default")?.as_str()?;
+        Some(registry.to_owned())
+    })
+}
+
 fn env_rustup_toolchain() -> Option<String> {
     let val = env::var("RUSTUP_TOOLCHAIN").ok()?;
     Some(val.trim().to_owned())
 }
 
 fn execute_rustup_override_list(cwd: &Path) -> Option<String> {
-    let Output { stdout, .. } = Command::new("rustup")
-        .args(&["override", "list"])
-        .output()
-        .ok()?;
-    let stdout = String::from_utf8(stdout).ok()?;
-    extract_toolchain_from_rustup_override_list(&stdout, cwd)
+    let output = utils::exec_cmd_allow_failure("rustup", &["override", "list"])?;
+    extract_toolchain_from_rustup_override_list(&output.stdout, cwd)
 }
 
 fn extract_toolchain_from_rustup_override_list(stdout: &str, cwd: &Path) -> Option<String> {
@@ -149,10 +198,7 @@ fn extract_toolchain_from_rustup_run_rustc_version(output: Output) -> RustupRunR
 }
 
 fn execute_rustc_version() -> Option<String> {
-    match Command::new("rustc").arg("--version").output() {
-        Ok(output) => Some(String::from_utf8(output.stdout).unwrap()),
-        Err(_) => None,
-    }
+    Some(utils::exec_cmd_allow_failure("rustc", &["--version"])?.stdout)
 }
 
 fn format_rustc_version(mut rustc_stdout: String) -> String {
@@ -268,6 +314,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_cargo_config_registry() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cargo_dir = dir.path().join(".cargo");
+        fs::create_dir(&cargo_dir)?;
+        assert_eq!(read_cargo_config_registry(&cargo_dir), None);
+
+        fs::write(
+            cargo_dir.join("config.toml"),
+            "[registries.my-registry]\n\
+             index = \"https://my-intranet:8080/git/index\"\n\
+             \n\
+             [registry]\n\
+             default = \"my-registry\"\n",
+        )?;
+        assert_eq!(
+            read_cargo_config_registry(&cargo_dir),
+            Some("my-registry".to_owned())
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn test_find_cargo_config_registry_in_parent() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cargo_dir = dir.path().join(".cargo");
+        let project_dir = dir.path().join("project");
+        fs::create_dir(&cargo_dir)?;
+        fs::create_dir(&project_dir)?;
+        fs::write(
+            cargo_dir.join("config"),
+            "[registry]\ndefault = \"my-registry\"\n",
+        )?;
+
+        assert_eq!(
+            find_cargo_config_registry(project_dir.ancestors()),
+            Some("my-registry".to_owned())
+        );
+        dir.close()
+    }
+
+    #[test]
+    fn render_custom_registry() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::File::create(dir.path().join("Cargo.toml"))?.sync_all()?;
+        fs::create_dir(dir.path().join(".cargo"))?;
+        fs::write(
+            dir.path().join(".cargo").join("config.toml"),
+            "[registry]\ndefault = \"my-registry\"\n",
+        )?;
+
+        let actual = crate::modules::utils::test::render_module("rust", dir.path(), None).unwrap();
+        assert!(actual.contains(" (my-registry)"), "{:?}", actual);
+        dir.close()
+    }
+
+    #[test]
+    fn render_hides_crates_io_registry() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::File::create(dir.path().join("Cargo.toml"))?.sync_all()?;
+        fs::create_dir(dir.path().join(".cargo"))?;
+        fs::write(
+            dir.path().join(".cargo").join("config.toml"),
+            "[registry]\ndefault = \"crates-io\"\n",
+        )?;
+
+        let actual = crate::modules::utils::test::render_module("rust", dir.path(), None).unwrap();
+        assert!(actual.contains(" v"), "{:?}", actual);
+        assert!(!actual.contains("crates-io"), "{:?}", actual);
+        assert!(!actual.contains(" ("), "{:?}", actual);
+        dir.close()
+    }
+
     #[test]
     fn test_format_rustc_version() {
         let nightly_input = String::from("rustc 1.34.0-nightly (b139669f3 2019-04-10)");
@@ -0,0 +1,46 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::sops::SopsConfig;
+
+/// Creates a module showing whether SOPS is configured and an age key is available
+///
+/// Will display if the current directory contains a `.sops.yaml` file. The key is
+/// considered available if `$SOPS_AGE_KEY` or `$SOPS_AGE_KEY_FILE` is set.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_sops_project = context
+        .try_begin_scan()?
+        .set_files(&[".sops.yaml"])
+        .is_match();
+
+    if !is_sops_project {
+        return None;
+    }
+
+    let mut module = context.new_module("sops");
+    let config: SopsConfig = SopsConfig::try_load(module.config);
+
+    let key_available = ["SOPS_AGE_KEY", "SOPS_AGE_KEY_FILE"]
+        .iter()
+        .filter_map(|variable| context.get_env(variable))
+        .any(|value| !value.trim().is_empty());
+
+    if key_available {
+        module.set_style(config.style);
+    } else {
+        module.set_style(config.key_missing_style);
+    }
+
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    if key_available {
+        module.create_segment("key", &config.key_available);
+    } else {
+        module.create_segment("key", &config.key_missing);
+    }
+
+    Some(module)
+}
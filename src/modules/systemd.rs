@@ -0,0 +1,93 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::systemd::SystemdConfig;
+use crate::utils;
+
+/// Creates a module showing that the shell runs inside a systemd unit
+///
+/// Will display if `$INVOCATION_ID` or `$JOURNAL_STREAM` is set, which systemd
+/// does for every process it starts. The unit name is read from the cgroup of the
+/// current process, where available.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_systemd_unit = ["INVOCATION_ID", "JOURNAL_STREAM"]
+        .iter()
+        .any(|key| context.get_env(key).is_some_and(|value| !value.is_empty()));
+    if !is_systemd_unit {
+        return None;
+    }
+
+    let mut module = context.new_module("systemd");
+    let config: SystemdConfig = SystemdConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("in ");
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+
+    if config.show_unit {
+        let unit = utils::read_file("/proc/self/cgroup")
+            .ok()
+            .and_then(|cgroup| get_unit_name(&cgroup));
+        if let Some(unit) = unit {
+            module.create_segment("unit", &SegmentConfig::new(&unit));
+        }
+    }
+
+    Some(module)
+}
+
+/// Find the innermost systemd service or scope in the contents of `/proc/<pid>/cgroup`.
+///
+/// With the unified hierarchy this is a single `0::/system.slice/nginx.service` line,
+/// with the legacy hierarchy it is the `name=systemd` controller's line.
+fn get_unit_name(cgroup: &str) -> Option<String> {
+    cgroup
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let controllers = fields.nth(1)?;
+            let path = fields.next()?;
+            if controllers.is_empty() || controllers == "name=systemd" {
+                Some(path)
+            } else {
+                None
+            }
+        })
+        .flat_map(|path| path.rsplit('/'))
+        .find(|component| component.ends_with(".service") || component.ends_with(".scope"))
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_name_unified_hierarchy() {
+        let cgroup = "0::/system.slice/nginx.service\n";
+        assert_eq!(get_unit_name(cgroup), Some(String::from("nginx.service")));
+    }
+
+    #[test]
+    fn unit_name_legacy_hierarchy() {
+        let cgroup = "\
+12:memory:/system.slice/cron.service
+1:name=systemd:/user.slice/user-1000.slice/user@1000.service/app.slice/backup.service
+";
+        assert_eq!(get_unit_name(cgroup), Some(String::from("backup.service")));
+    }
+
+    #[test]
+    fn unit_name_without_unit() {
+        assert_eq!(get_unit_name("0::/\n"), None);
+        assert_eq!(get_unit_name(""), None);
+    }
+}
@@ -0,0 +1,180 @@
+use regex::Regex;
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::task_runner::TaskRunnerConfig;
+use crate::utils;
+
+const TASK_FILES: &[&str] = &["Taskfile.yml", "Taskfile.yaml"];
+const JUST_FILES: &[&str] = &["justfile", "Justfile", ".justfile"];
+const MAKE_FILES: &[&str] = &["Makefile", "makefile", "GNUmakefile"];
+
+/// Creates a module with the task runner used by the current project
+///
+/// Will display the first runner in `detect_order` whose file is present:
+///     - `task` for a `Taskfile.yml` or `Taskfile.yaml` (go-task)
+///     - `just` for a `justfile`
+///     - `make` for a `Makefile`
+///
+/// For task and just, the number of available tasks is shown too.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let dir_contents = context.dir_contents().ok()?;
+
+    let mut module = context.new_module("task_runner");
+    let config: TaskRunnerConfig = TaskRunnerConfig::try_load(module.config);
+
+    if config.disabled {
+        return None;
+    }
+
+    let (runner, file) = config.detect_order.iter().find_map(|runner| {
+        let files = match *runner {
+            "task" => TASK_FILES,
+            "just" => JUST_FILES,
+            "make" => MAKE_FILES,
+            _ => {
+                log::debug!("Unknown task runner in detect_order: {}", runner);
+                return None;
+            }
+        };
+        let file = files.iter().find(|file| dir_contents.has_file(file))?;
+        Some((*runner, *file))
+    })?;
+
+    let symbol = match runner {
+        "task" => &config.task_symbol,
+        "just" => &config.just_symbol,
+        _ => &config.make_symbol,
+    };
+
+    module.set_style(config.style);
+    module.create_segment("symbol", symbol);
+
+    if config.show_count {
+        let content = utils::read_file(context.current_dir.join(file)).ok();
+        let task_count = content.and_then(|content| match runner {
+            "task" => count_taskfile_tasks(&content),
+            "just" => count_justfile_recipes(&content),
+            _ => None,
+        });
+        if let Some(task_count) = task_count {
+            module.create_segment("count", &SegmentConfig::new(&format!(" ({})", task_count)));
+        }
+    }
+
+    Some(module)
+}
+
+/// Count the entries of the top-level `tasks` mapping of a Taskfile
+fn count_taskfile_tasks(content: &str) -> Option<usize> {
+    let docs = YamlLoader::load_from_str(content).ok()?;
+    let tasks = docs.first()?["tasks"].as_hash()?;
+    Some(tasks.len())
+}
+
+/// Count the public recipes of a justfile, as listed by `just --list`
+///
+/// Recipes start at the beginning of a line with their name, optionally followed by
+/// parameters, and a single colon. Assignments, aliases and settings use `:=`, and
+/// recipes whose name starts with an underscore are private.
+fn count_justfile_recipes(content: &str) -> Option<usize> {
+    let recipe = Regex::new(r"^@?[A-Za-z][A-Za-z0-9_-]*(\s[^:]*)?:([^=]|$)").unwrap();
+    let count = content.lines().filter(|line| recipe.is_match(line)).count();
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::utils::test::render_module;
+    use ansi_term::Color;
+    use std::fs::{self, File};
+    use std::io;
+
+    const TASKFILE: &str = "version: '3'\n\ntasks:\n  build:\n    cmds:\n      - go build\n  test:\n    cmds:\n      - go test ./...\n";
+    const JUSTFILE: &str = "set shell := [\"bash\", \"-c\"]\nversion := \"1.0\"\nalias b := build\n\n# Build the project\nbuild:\n    cargo build\n\ntest filter='': build\n    cargo test {{filter}}\n\n@lint:\n    cargo clippy\n\n_helper:\n    echo hidden\n";
+
+    #[test]
+    fn test_count_taskfile_tasks() {
+        assert_eq!(count_taskfile_tasks(TASKFILE), Some(2));
+        assert_eq!(count_taskfile_tasks("version: '3'\n"), None);
+    }
+
+    #[test]
+    fn test_count_justfile_recipes() {
+        assert_eq!(count_justfile_recipes(JUSTFILE), Some(3));
+    }
+
+    fn enabled() -> Option<toml::Value> {
+        Some(toml::toml! {
+            [task_runner]
+            disabled = false
+        })
+    }
+
+    #[test]
+    fn disabled_by_default() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Makefile"))?.sync_all()?;
+        let actual = render_module("task_runner", dir.path(), None);
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_without_task_runner() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let actual = render_module("task_runner", dir.path(), enabled());
+        let expected = None;
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_taskfile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("Taskfile.yml"), TASKFILE)?;
+        let actual = render_module("task_runner", dir.path(), enabled());
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("task (2)")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_justfile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("justfile"), JUSTFILE)?;
+        let actual = render_module("task_runner", dir.path(), enabled());
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("just (3)")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_makefile() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        File::create(dir.path().join("Makefile"))?.sync_all()?;
+        let actual = render_module("task_runner", dir.path(), enabled());
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("make")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_custom_detect_order() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("justfile"), JUSTFILE)?;
+        File::create(dir.path().join("Makefile"))?.sync_all()?;
+        let config = toml::toml! {
+            [task_runner]
+            disabled = false
+            detect_order = ["make", "just"]
+        };
+        let actual = render_module("task_runner", dir.path(), Some(config));
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("make")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+}
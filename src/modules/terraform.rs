@@ -9,15 +9,23 @@ use std::path::PathBuf;
 /// Creates a module with the current Terraform version and workspace
 ///
 /// Will display the Terraform version and workspace if any of the following criteria are met:
-///     - Current directory contains a `.terraform` directory
+///     - Current directory, or a folder within `scan_depth`, contains a `.terraform` directory
 ///     - Current directory contains a file with the `.tf` extension
+///     - Current directory contains a `terragrunt.hcl` or `root.hcl` file, in which case
+///       the Terragrunt symbol and version are shown instead
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let is_terraform_project = context
+    let is_terragrunt_project = context
         .try_begin_scan()?
-        .set_folders(&[".terraform"])
-        .set_extensions(&["tf"])
+        .set_files(&["terragrunt.hcl", "root.hcl"])
         .is_match();
 
+    let is_terraform_project = is_terragrunt_project
+        || context
+            .try_begin_scan()?
+            .set_folders_recursive(&[".terraform"])
+            .set_extensions(&["tf"])
+            .is_match();
+
     if !is_terraform_project {
         return None;
     }
@@ -26,12 +34,26 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config: TerraformConfig = TerraformConfig::try_load(module.config);
 
     module.set_style(config.style);
-    module.create_segment("symbol", &config.symbol);
+    if is_terragrunt_project {
+        module.create_segment("terragrunt_symbol", &config.terragrunt_symbol);
+    } else {
+        module.create_segment(
+            "symbol",
+            context.symbol_style().select(
+                &config.symbol,
+                &config.unicode_symbol,
+                &config.ascii_symbol,
+            ),
+        );
+    }
 
     if config.show_version {
-        let terraform_version =
-            format_terraform_version(&utils::exec_cmd("terraform", &["version"])?.stdout.as_str())?;
-        module.create_segment("version", &config.version.with_value(&terraform_version));
+        let version = if is_terragrunt_project {
+            get_terragrunt_version()?
+        } else {
+            get_terraform_version()?
+        };
+        module.create_segment("version", &config.version.with_value(&version));
     }
 
     let terraform_workspace = &get_terraform_workspace(&context.current_dir)?;
@@ -63,6 +85,19 @@ fn get_terraform_workspace(cwd: &PathBuf) -> Option<String> {
     }
 }
 
+fn get_terraform_version() -> Option<String> {
+    // Terraform 0.13 and later can print their version as JSON, which doesn't
+    // include the notice about being out of date
+    let json = utils::exec_cmd_json::<serde_json::Value>("terraform", &["version", "-json"]);
+    if let Some(version) = json
+        .as_ref()
+        .and_then(|json| json["terraform_version"].as_str())
+    {
+        return Some(format!("v{} ", version));
+    }
+    format_terraform_version(&utils::exec_cmd("terraform", &["version"])?.stdout)
+}
+
 fn format_terraform_version(version: &str) -> Option<String> {
     // `terraform version` output looks like this
     // Terraform v0.12.14
@@ -78,10 +113,36 @@ fn format_terraform_version(version: &str) -> Option<String> {
     )
 }
 
+fn get_terragrunt_version() -> Option<String> {
+    // `terragrunt --version` output looks like this
+    // terragrunt version v0.45.0
+    let output = utils::exec_cmd("terragrunt", &["--version"])?;
+    let version = output
+        .stdout
+        .lines()
+        .next()?
+        .trim_start_matches("terragrunt version")
+        .trim();
+    if version.is_empty() {
+        return None;
+    }
+    Some(version.to_owned() + " ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_terraform_version_json() {
+        assert_eq!(get_terraform_version(), Some("v1.5.7 ".to_string()));
+    }
+
+    #[test]
+    fn test_get_terragrunt_version() {
+        assert_eq!(get_terragrunt_version(), Some("v0.45.0 ".to_string()));
+    }
+
     #[test]
     fn test_format_terraform_version_release() {
         let input = "Terraform v0.12.14";
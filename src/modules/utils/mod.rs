@@ -1,5 +1,6 @@
 pub mod directory;
 pub mod java_version_parser;
+pub mod region;
 
 #[cfg(test)]
 pub mod test;
@@ -0,0 +1,109 @@
+/// Short codes for the location words that make up AWS and GCP region names
+const LOCATION_CODES: &[(&str, &str)] = &[
+    ("northamerica", "na"),
+    ("southamerica", "sa"),
+    ("europe", "eu"),
+    ("asia", "ap"),
+    ("australia", "au"),
+    ("northeast", "ne"),
+    ("northwest", "nw"),
+    ("southeast", "se"),
+    ("southwest", "sw"),
+    ("northcentral", "nc"),
+    ("southcentral", "sc"),
+    ("north", "n"),
+    ("south", "s"),
+    ("east", "e"),
+    ("west", "w"),
+    ("central", "c"),
+];
+
+/// Azure region names have no separators, so they are looked up directly
+const AZURE_REGIONS: &[(&str, &str)] = &[
+    ("eastus", "use1"),
+    ("eastus2", "use2"),
+    ("westus", "usw1"),
+    ("westus2", "usw2"),
+    ("westus3", "usw3"),
+    ("centralus", "usc1"),
+    ("northcentralus", "usnc1"),
+    ("southcentralus", "ussc1"),
+    ("canadacentral", "cac1"),
+    ("brazilsouth", "sas1"),
+    ("northeurope", "eun1"),
+    ("westeurope", "euw1"),
+    ("uksouth", "uks1"),
+    ("ukwest", "ukw1"),
+    ("eastasia", "ape1"),
+    ("southeastasia", "apse1"),
+    ("japaneast", "apne1"),
+    ("australiaeast", "aue1"),
+];
+
+/// Normalize a cloud provider's region name to a short code, so that the same
+/// location is displayed the same way for every provider.
+///
+/// `us-east-1` (AWS), `us-east1` (GCP) and `eastus` (Azure) all become `use1`.
+/// Returns `None` for names that don't look like a region.
+pub fn short_region(region: &str) -> Option<String> {
+    if let Some((_, code)) = AZURE_REGIONS.iter().find(|(name, _)| *name == region) {
+        return Some((*code).to_string());
+    }
+
+    let mut words: Vec<&str> = region.split('-').collect();
+    if words.len() < 2 {
+        return None;
+    }
+
+    // AWS has the number as its own word, GCP appends it to the last word
+    let last = words.pop()?;
+    let word_end = last.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = &last[word_end.len()..];
+    if number.is_empty() {
+        return None;
+    }
+    if !word_end.is_empty() {
+        words.push(word_end);
+    }
+
+    let location: String = words
+        .iter()
+        .map(|word| {
+            LOCATION_CODES
+                .iter()
+                .find(|(name, _)| name == word)
+                .map_or(*word, |(_, code)| code)
+        })
+        .collect();
+
+    Some(format!("{}{}", location, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_region_aws() {
+        assert_eq!(short_region("us-east-1"), Some("use1".to_string()));
+        assert_eq!(short_region("ap-southeast-2"), Some("apse2".to_string()));
+    }
+
+    #[test]
+    fn test_short_region_gcp() {
+        assert_eq!(short_region("us-east1"), Some("use1".to_string()));
+        assert_eq!(short_region("europe-west4"), Some("euw4".to_string()));
+    }
+
+    #[test]
+    fn test_short_region_azure() {
+        assert_eq!(short_region("eastus"), Some("use1".to_string()));
+        assert_eq!(short_region("westeurope"), Some("euw1".to_string()));
+    }
+
+    #[test]
+    fn test_short_region_unknown() {
+        assert_eq!(short_region("global"), None);
+        assert_eq!(short_region("us-east"), None);
+    }
+}
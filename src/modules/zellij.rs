@@ -0,0 +1,28 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::zellij::ZellijConfig;
+
+/// Creates a module with the name of the active zellij session
+///
+/// Will display the session name if `$ZELLIJ_SESSION_NAME` is set, which zellij
+/// does for every shell it spawns alongside `$ZELLIJ`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let session_name = context
+        .get_env("ZELLIJ_SESSION_NAME")
+        .filter(|name| !name.trim().is_empty())?;
+
+    let mut module = context.new_module("zellij");
+    let config: ZellijConfig = ZellijConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("in ");
+    module.create_segment(
+        "symbol",
+        context
+            .symbol_style()
+            .select(&config.symbol, &config.unicode_symbol, &config.ascii_symbol),
+    );
+    module.create_segment("session", &SegmentConfig::new(session_name.trim()));
+
+    Some(module)
+}
@@ -1,8 +1,13 @@
 use ansi_term::ANSIStrings;
 use clap::ArgMatches;
+use once_cell::sync::OnceCell;
 use rayon::prelude::*;
+use rayon::ThreadPool;
+use std::collections::HashMap;
 use std::fmt::{self, Debug, Write as FmtWrite};
 use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use unicode_width::UnicodeWidthChar;
 
 use crate::context::{Context, Shell};
@@ -18,6 +23,11 @@ pub fn prompt(args: ArgMatches) {
 }
 
 pub fn get_prompt(context: Context) -> String {
+    // Fall back to a minimal prompt if starship has been disabled through the environment
+    if context.get_env_bool("STARSHIP_DISABLE") == Some(true) {
+        return get_minimal_prompt(&context);
+    }
+
     let config = context.config.get_root_config();
     let mut buf = String::new();
 
@@ -32,7 +42,11 @@ pub fn get_prompt(context: Context) -> String {
         buf.push_str("\x1b[J"); // An ASCII control code to clear screen
     }
 
-    let modules = compute_modules(&context);
+    let (modules, timings) = compute_modules_with_timings(&context);
+
+    if config.debug_to_stderr {
+        eprint!("{}", format_module_timings(&timings, context.elapsed()));
+    }
 
     let mut print_without_prefix = true;
     let printable = modules.iter();
@@ -53,6 +67,18 @@ pub fn get_prompt(context: Context) -> String {
     buf
 }
 
+/// Render only the `character` module
+fn get_minimal_prompt(context: &Context) -> String {
+    modules::handle("character", context)
+        .map(|mut module| {
+            if context.is_no_color() {
+                module.clear_styles();
+            }
+            module.to_string_without_prefix(context.shell)
+        })
+        .unwrap_or_default()
+}
+
 pub fn module(module_name: &str, args: ArgMatches) {
     let context = Context::new(args);
     let module = get_module(module_name, context).unwrap_or_default();
@@ -60,7 +86,12 @@ pub fn module(module_name: &str, args: ArgMatches) {
 }
 
 pub fn get_module(module_name: &str, context: Context) -> Option<String> {
-    modules::handle(module_name, &context).map(|m| m.to_string())
+    modules::handle(module_name, &context).map(|mut module| {
+        if context.is_no_color() {
+            module.clear_styles();
+        }
+        module.to_string()
+    })
 }
 
 pub fn explain(args: ArgMatches) {
@@ -99,39 +130,45 @@ pub fn explain(args: ArgMatches) {
         max_module_width = std::cmp::max(max_module_width, info.value_len);
     }
 
-    let desc_width = term_size::dimensions()
-        .map(|(w, _)| w)
-        .map(|width| width - std::cmp::min(width, max_ansi_module_width));
+    let desc_width = context.width - std::cmp::min(context.width, max_ansi_module_width);
 
     println!("\n Here's a breakdown of your prompt:");
     for info in modules {
         let wide_chars = count_wide_chars(&info.value);
 
-        if let Some(desc_width) = desc_width {
-            let wrapped = textwrap::fill(&info.desc, desc_width);
-            let mut lines = wrapped.split('\n');
-            println!(
-                " {:width$}  -  {}",
-                info.value,
-                lines.next().unwrap(),
-                width = max_ansi_module_width - wide_chars
-            );
+        let wrapped = textwrap::fill(&info.desc, desc_width);
+        let mut lines = wrapped.split('\n');
+        println!(
+            " {:width$}  -  {}",
+            info.value,
+            lines.next().unwrap(),
+            width = max_ansi_module_width - wide_chars
+        );
 
-            for line in lines {
-                println!("{}{}", " ".repeat(max_module_width + 6), line.trim());
-            }
-        } else {
-            println!(
-                " {:width$}  -  {}",
-                info.value,
-                info.desc,
-                width = max_ansi_module_width - wide_chars
-            );
-        };
+        for line in lines {
+            println!("{}{}", " ".repeat(max_module_width + 6), line.trim());
+        }
+    }
+
+    if context.dir_scan_was_truncated() {
+        println!(
+            "\n Scanning the current directory took longer than `scan_timeout`, so some modules may be missing."
+        );
     }
 }
 
+/// How a module fared while rendering the prompt
+struct ModuleTiming {
+    name: String,
+    rendered: bool,
+    duration: Duration,
+}
+
 fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
+    compute_modules_with_timings(context).0
+}
+
+fn compute_modules_with_timings<'a>(context: &'a Context) -> (Vec<Module<'a>>, Vec<ModuleTiming>) {
     enum Mod<'a> {
         Builtin(&'a str),
         Custom(&'a str),
@@ -195,14 +232,98 @@ fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
         }
     }
 
-    prompt_order
-        .par_iter()
-        .map(|module| match module {
-            Mod::Builtin(builtin) => modules::handle(builtin, context),
-            Mod::Custom(custom) => modules::custom::module(custom, context),
-        }) // Compute segments
-        .flatten() // Remove segments set to `None`
-        .collect::<Vec<Module<'a>>>()
+    let compute_module = |module: &Mod<'a>| {
+        let start = Instant::now();
+        let (name, rendered) = match module {
+            Mod::Builtin(builtin) => (builtin.to_string(), modules::handle(builtin, context)),
+            Mod::Custom(custom) => (
+                format!("custom.{}", custom),
+                modules::custom::module(custom, context),
+            ),
+        };
+        let timing = ModuleTiming {
+            name,
+            rendered: rendered.is_some(),
+            duration: start.elapsed(),
+        };
+        (rendered, timing)
+    };
+    let compute_parallel = || {
+        prompt_order
+            .par_iter()
+            .map(compute_module) // Compute segments
+            .collect::<Vec<(Option<Module<'a>>, ModuleTiming)>>()
+    };
+
+    let computed = match context.config.get_root_config().render_threads {
+        // Let rayon size its global thread pool to the number of CPUs
+        0 => compute_parallel(),
+        // Render on the current thread, one module after the other
+        1 => prompt_order.iter().map(compute_module).collect(),
+        threads => match render_pool(threads) {
+            Some(pool) => pool.install(compute_parallel),
+            None => prompt_order.iter().map(compute_module).collect(),
+        },
+    };
+
+    let mut modules = Vec::new();
+    let mut timings = Vec::new();
+    for (module, timing) in computed {
+        // Remove segments set to `None`
+        modules.extend(module);
+        timings.push(timing);
+    }
+
+    if context.is_no_color() {
+        modules.iter_mut().for_each(Module::clear_styles);
+    }
+
+    (modules, timings)
+}
+
+/// The thread pool used to render modules when `render_threads` is set, built the
+/// first time a pool of that size is needed and reused for later renders
+fn render_pool(threads: usize) -> Option<Arc<ThreadPool>> {
+    static POOLS: OnceCell<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceCell::new();
+
+    let mut pools = POOLS.get_or_init(Default::default).lock().ok()?;
+    if let Some(pool) = pools.get(&threads) {
+        return Some(Arc::clone(pool));
+    }
+
+    match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+        Ok(pool) => {
+            let pool = Arc::new(pool);
+            pools.insert(threads, Arc::clone(&pool));
+            Some(pool)
+        }
+        Err(error) => {
+            log::debug!(
+                "Unable to build a pool of {} threads, rendering sequentially: {}",
+                threads,
+                error
+            );
+            None
+        }
+    }
+}
+
+/// Summarize which modules ran and how long each took, one tab-separated line
+/// per module followed by the `total` time spent rendering, for `debug_to_stderr`
+fn format_module_timings(timings: &[ModuleTiming], total: Duration) -> String {
+    let mut summary: String = timings
+        .iter()
+        .map(|timing| {
+            let status = if timing.rendered {
+                "rendered"
+            } else {
+                "hidden"
+            };
+            format!("{}\t{}\t{:?}\n", timing.name, status, timing.duration)
+        })
+        .collect();
+    summary.push_str(&format!("total\t\t{:?}\n", total));
+    summary
 }
 
 fn should_add_implicit_custom_module(
@@ -231,3 +352,64 @@ fn should_add_implicit_custom_module(
 fn count_wide_chars(value: &str) -> usize {
     value.chars().filter(|c| c.width().unwrap_or(0) > 1).count()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StarshipConfig;
+
+    fn render_character_prompt(shell: Shell) -> String {
+        let mut context = Context::new_with_shell(shell);
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                add_newline = false
+                prompt_order = ["character"]
+            }),
+        };
+        get_prompt(context)
+    }
+
+    #[test]
+    fn prompt_escapes_are_wrapped_for_shell() {
+        let bash = render_character_prompt(Shell::Bash);
+        assert!(bash.starts_with("\u{5c}[\u{1b}["));
+        assert!(bash.contains("m\u{5c}]"));
+
+        let zsh = render_character_prompt(Shell::Zsh);
+        assert!(zsh.starts_with("%{\u{1b}["));
+        assert!(zsh.contains("m%}"));
+
+        let fish = render_character_prompt(Shell::Fish);
+        assert!(fish.starts_with("\x1b[J\u{1b}["));
+        assert!(!fish.contains("%{") && !fish.contains("\u{5c}["));
+    }
+
+    #[test]
+    fn render_pool_is_reused() {
+        let pool = render_pool(2).unwrap();
+        assert_eq!(pool.current_num_threads(), 2);
+        assert!(Arc::ptr_eq(&pool, &render_pool(2).unwrap()));
+        assert_eq!(render_pool(3).unwrap().current_num_threads(), 3);
+    }
+
+    #[test]
+    fn module_timings_cover_prompt_order() {
+        let mut context = Context::new_with_shell(Shell::Unknown);
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                prompt_order = ["line_break", "jobs", "character"]
+            }),
+        };
+
+        let (modules, timings) = compute_modules_with_timings(&context);
+        assert_eq!(modules.len(), 2);
+
+        let summary = format_module_timings(&timings, context.elapsed());
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("line_break\trendered\t"));
+        assert!(lines[1].starts_with("jobs\thidden\t"));
+        assert!(lines[2].starts_with("character\trendered\t"));
+        assert!(lines[3].starts_with("total\t\t"));
+    }
+}
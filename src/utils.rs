@@ -3,6 +3,8 @@ use std::io::{Read, Result};
 use std::path::Path;
 use std::process::Command;
 
+use serde::de::DeserializeOwned;
+
 use crate::context::Shell;
 
 /// Return the string contents of a file
@@ -18,11 +20,27 @@ pub fn read_file<P: AsRef<Path>>(file_name: P) -> Result<String> {
 pub struct CommandOutput {
     pub stdout: String,
     pub stderr: String,
+    /// The exit code, or `None` if the command was terminated by a signal
+    pub exit_code: Option<i32>,
+}
+
+impl CommandOutput {
+    /// Returns stdout followed by stderr, for tools that don't consistently use
+    /// one of them. Both are joined by a newline if neither is empty.
+    pub fn combined(&self) -> String {
+        if self.stdout.is_empty() || self.stderr.is_empty() {
+            format!("{}{}", self.stdout, self.stderr)
+        } else {
+            format!("{}\n{}", self.stdout, self.stderr)
+        }
+    }
 }
 
 impl PartialEq for CommandOutput {
     fn eq(&self, other: &Self) -> bool {
-        self.stdout == other.stdout && self.stderr == other.stderr
+        self.stdout == other.stdout
+            && self.stderr == other.stderr
+            && self.exit_code == other.exit_code
     }
 }
 
@@ -34,6 +52,25 @@ pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
 
 #[cfg(test)]
 pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+    mock_cmd(&cmd, &args).or_else(|| internal_exec_cmd(&cmd, &args))
+}
+
+/// Execute a command and return its output and exit code, even if it failed.
+///
+/// Returns `None` only if the command could not be started.
+#[cfg(not(test))]
+pub fn exec_cmd_allow_failure(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+    internal_exec_cmd_allow_failure(&cmd, &args)
+}
+
+#[cfg(test)]
+pub fn exec_cmd_allow_failure(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+    mock_cmd(&cmd, &args).or_else(|| internal_exec_cmd_allow_failure(&cmd, &args))
+}
+
+/// The output of the commands mocked for tests, or `None` if `cmd` isn't mocked
+#[cfg(test)]
+fn mock_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
     let command = match args.len() {
         0 => String::from(cmd),
         _ => format!("{} {}", cmd, args.join(" ")),
@@ -42,43 +79,79 @@ pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
         "crystal --version" => Some(CommandOutput {
             stdout: String::from("Crystal 0.32.1 (2019-12-18)"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
         "dummy_command" => Some(CommandOutput {
             stdout: String::from("stdout ok!"),
             stderr: String::from("stderr ok!"),
+            exit_code: Some(0),
+        }),
+        "buf --version" => Some(CommandOutput {
+            stdout: String::from("1.28.1\n"),
+            stderr: String::default(),
+            exit_code: Some(0),
+        }),
+        "earthly --version" => Some(CommandOutput {
+            stdout: String::from("earthly version v0.8.4 8e1e4b0c linux/amd64; Ubuntu 22.04\n"),
+            stderr: String::default(),
+            exit_code: Some(0),
         }),
         "elm --version" => Some(CommandOutput {
             stdout: String::from("0.19.1"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
         "go version" => Some(CommandOutput {
             stdout: String::from("go version go1.12.1 linux/amd64"),
             stderr: String::default(),
+            exit_code: Some(0),
+        }),
+        "helm version --short --client" => Some(CommandOutput {
+            stdout: String::from("v3.12.0+gc9f554d\n"),
+            stderr: String::default(),
+            exit_code: Some(0),
         }),
         "julia --version" => Some(CommandOutput {
             stdout: String::from("julia version 1.4.0"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
         "node --version" => Some(CommandOutput {
             stdout: String::from("v12.0.0"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
         "php -r echo PHP_MAJOR_VERSION.'.'.PHP_MINOR_VERSION.'.'.PHP_RELEASE_VERSION;" => {
             Some(CommandOutput {
                 stdout: String::from("7.3.8"),
                 stderr: String::default(),
+                exit_code: Some(0),
             })
         }
         "ruby -v" => Some(CommandOutput {
             stdout: String::from("ruby 2.5.1p57 (2018-03-29 revision 63029) [x86_64-linux-gnu]"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
         "stack --no-install-ghc --lock-file read-only ghc -- --numeric-version" => {
             Some(CommandOutput {
                 stdout: String::from("8.6.5"),
                 stderr: String::default(),
+                exit_code: Some(0),
             })
         }
+        "terraform version -json" => Some(CommandOutput {
+            stdout: String::from(
+                r#"{"terraform_version":"1.5.7","platform":"linux_amd64","provider_selections":{},"terraform_outdated":false}"#,
+            ),
+            stderr: String::default(),
+            exit_code: Some(0),
+        }),
+        "terragrunt --version" => Some(CommandOutput {
+            stdout: String::from("terragrunt version v0.45.0\n"),
+            stderr: String::default(),
+            exit_code: Some(0),
+        }),
         "elixir --version" => Some(CommandOutput {
             stdout: String::from(
                 "\
@@ -87,14 +160,60 @@ Erlang/OTP 22 [erts-10.6.4] [source] [64-bit] [smp:8:8] [ds:8:8:10] [async-threa
 Elixir 1.10 (compiled with Erlang/OTP 22)",
             ),
             stderr: String::default(),
+            exit_code: Some(0),
+        }),
+        "nats context info --json" => Some(CommandOutput {
+            stdout: String::from(
+                r#"{"name":"localhost","url":"nats://localhost:4222","description":"Local NATS server"}"#,
+            ),
+            stderr: String::default(),
+            exit_code: Some(0),
         }),
         s if s.starts_with("erl") => Some(CommandOutput {
             stdout: String::from("22.1.3"),
             stderr: String::default(),
+            exit_code: Some(0),
         }),
-        // If we don't have a mocked command fall back to executing the command
-        _ => internal_exec_cmd(&cmd, &args),
+        // If we don't have a mocked command the caller executes it
+        _ => None,
+    }
+}
+
+/// Execute a command and deserialize its stdout as JSON
+///
+/// Returns `None` if the command fails or its output doesn't match `T`.
+pub fn exec_cmd_json<T: DeserializeOwned>(cmd: &str, args: &[&str]) -> Option<T> {
+    let output = exec_cmd(cmd, args)?;
+    match serde_json::from_str(&output.stdout) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            log::debug!(
+                "Unable to parse JSON output of \"{} {}\": {}",
+                cmd,
+                args.join(" "),
+                error
+            );
+            None
+        }
+    }
+}
+
+/// Encode bytes as a lowercase hex string, two digits per byte
+pub fn encode_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode a hex string, as produced by `encode_to_hex`, back into bytes
+///
+/// Returns `None` if the string has an odd length or contains a non-hex digit.
+pub fn decode_from_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
     }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
 }
 
 /// Wraps ANSI color escape sequences in the shell-appropriate wrappers.
@@ -128,14 +247,20 @@ pub fn wrap_seq_for_shell(
                 escaped = true;
                 match shell {
                     Shell::Bash => format!("{}{}", BASH_BEG, escape_begin),
-                    Shell::Zsh => format!("{}{}", ZSH_BEG, escape_begin),
+                    Shell::Zsh | Shell::Murex => format!("{}{}", ZSH_BEG, escape_begin),
+                    // cmd (through clink) measures the prompt itself and skips escape sequences
+                    Shell::Cmd => x.to_string(),
+                    // fish has no zero-width markers; it skips the escape sequences it knows
+                    // about, both SGR and OSC, when measuring the prompt
+                    Shell::Fish => x.to_string(),
                     _ => x.to_string(),
                 }
             } else if x == escape_end && escaped {
                 escaped = false;
                 match shell {
                     Shell::Bash => format!("{}{}", escape_end, BASH_END),
-                    Shell::Zsh => format!("{}{}", escape_end, ZSH_END),
+                    Shell::Zsh | Shell::Murex => format!("{}{}", escape_end, ZSH_END),
+                    Shell::Cmd | Shell::Fish => x.to_string(),
                     _ => x.to_string(),
                 }
             } else {
@@ -147,6 +272,10 @@ pub fn wrap_seq_for_shell(
 }
 
 fn internal_exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+    internal_exec_cmd_allow_failure(cmd, args).filter(|output| output.exit_code == Some(0))
+}
+
+fn internal_exec_cmd_allow_failure(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
     log::trace!("Executing command {:?} with args {:?}", cmd, args);
     match Command::new(cmd).args(args).output() {
         Ok(output) => {
@@ -157,13 +286,10 @@ fn internal_exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
             log::trace!("stderr: {:?}", stderr_string);
             log::trace!("exit code: \"{:?}\"", output.status.code());
 
-            if !output.status.success() {
-                return None;
-            }
-
             Some(CommandOutput {
                 stdout: stdout_string,
                 stderr: stderr_string,
+                exit_code: output.status.code(),
             })
         }
         Err(error) => {
@@ -178,23 +304,106 @@ fn internal_exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn exec_mocked_command_json() {
+        #[derive(serde::Deserialize)]
+        struct NatsContext {
+            name: String,
+            url: String,
+        }
+
+        let context: NatsContext = exec_cmd_json("nats", &["context", "info", "--json"]).unwrap();
+        assert_eq!(context.name, "localhost");
+        assert_eq!(context.url, "nats://localhost:4222");
+
+        let invalid: Option<NatsContext> = exec_cmd_json("dummy_command", &[]);
+        assert!(invalid.is_none());
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let bytes: Vec<u8> = vec![0x00, 0x0f, 0x7a, 0xff];
+        assert_eq!(encode_to_hex(&bytes), "000f7aff");
+        assert_eq!(decode_from_hex(&encode_to_hex(&bytes)), Some(bytes));
+        assert_eq!(decode_from_hex(&encode_to_hex(&[])), Some(vec![]));
+        assert_eq!(
+            decode_from_hex("DEADbeef"),
+            Some(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+    }
+
+    #[test]
+    fn decode_hex_odd_length() {
+        assert_eq!(decode_from_hex("a"), None);
+        assert_eq!(decode_from_hex("abc"), None);
+        assert_eq!(decode_from_hex(&encode_to_hex(b"starship")[1..]), None);
+    }
+
+    #[test]
+    fn decode_hex_non_hex_digits() {
+        assert_eq!(decode_from_hex("zz"), None);
+        assert_eq!(decode_from_hex("0g"), None);
+        assert_eq!(decode_from_hex("+f"), None);
+        assert_eq!(decode_from_hex(" 0"), None);
+        // Two bytes long, so only the digit check can reject it
+        assert_eq!(decode_from_hex("é"), None);
+    }
+
     #[test]
     fn exec_mocked_command() {
         let result = exec_cmd("dummy_command", &[]);
         let expected = Some(CommandOutput {
             stdout: String::from("stdout ok!"),
             stderr: String::from("stderr ok!"),
+            exit_code: Some(0),
+        });
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn exec_mocked_command_allow_failure() {
+        let result = exec_cmd_allow_failure("dummy_command", &[]);
+        let expected = Some(CommandOutput {
+            stdout: String::from("stdout ok!"),
+            stderr: String::from("stderr ok!"),
+            exit_code: Some(0),
         });
 
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn command_output_combined() {
+        let both = CommandOutput {
+            stdout: String::from("hello"),
+            stderr: String::from("world"),
+            exit_code: Some(0),
+        };
+        assert_eq!(both.combined(), "hello\nworld");
+
+        let stdout_only = CommandOutput {
+            stdout: String::from("hello"),
+            stderr: String::default(),
+            exit_code: Some(0),
+        };
+        assert_eq!(stdout_only.combined(), "hello");
+
+        let stderr_only = CommandOutput {
+            stdout: String::default(),
+            stderr: String::from("world"),
+            exit_code: Some(0),
+        };
+        assert_eq!(stderr_only.combined(), "world");
+    }
+
     #[test]
     fn exec_no_output() {
         let result = internal_exec_cmd("true", &[]);
         let expected = Some(CommandOutput {
             stdout: String::from(""),
             stderr: String::from(""),
+            exit_code: Some(0),
         });
 
         assert_eq!(result, expected)
@@ -206,6 +415,7 @@ mod tests {
         let expected = Some(CommandOutput {
             stdout: String::from("hello\n"),
             stderr: String::from(""),
+            exit_code: Some(0),
         });
 
         assert_eq!(result, expected)
@@ -217,6 +427,7 @@ mod tests {
         let expected = Some(CommandOutput {
             stdout: String::from(""),
             stderr: String::from("hello\n"),
+            exit_code: Some(0),
         });
 
         assert_eq!(result, expected)
@@ -228,6 +439,7 @@ mod tests {
         let expected = Some(CommandOutput {
             stdout: String::from("hello\n"),
             stderr: String::from("world\n"),
+            exit_code: Some(0),
         });
 
         assert_eq!(result, expected)
@@ -241,6 +453,18 @@ mod tests {
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn exec_allow_failure_with_non_zero_exit_code() {
+        let result = internal_exec_cmd_allow_failure("/bin/sh", &["-c", "echo hello; exit 3"]);
+        let expected = Some(CommandOutput {
+            stdout: String::from("hello\n"),
+            stderr: String::from(""),
+            exit_code: Some(3),
+        });
+
+        assert_eq!(result, expected)
+    }
+
     #[test]
     fn test_color_sequence_wrappers() {
         let test0 = "\x1b2mhellomynamekeyes\x1b2m"; // BEGIN: \x1b     END: m
@@ -278,4 +502,63 @@ mod tests {
         assert_eq!(&bresult4, "herpaderp");
         assert_eq!(&bresult5, "");
     }
+
+    #[test]
+    fn test_color_sequence_wrappers_murex() {
+        let test0 = "\x1b2mhellomynamekeyes\x1b2m"; // BEGIN: \x1b     END: m
+        let test1 = "\x1b]330;mlol\x1b]0m"; // BEGIN: \x1b     END: m
+        let test2 = "\u{1b}J"; // BEGIN: \x1b     END: J
+        let test3 = "OH NO"; // BEGIN: O    END: O
+        let test4 = "herpaderp";
+        let test5 = "";
+
+        let mresult0 = wrap_seq_for_shell(test0.to_string(), Shell::Murex, '\x1b', 'm');
+        let mresult1 = wrap_seq_for_shell(test1.to_string(), Shell::Murex, '\x1b', 'm');
+        let mresult2 = wrap_seq_for_shell(test2.to_string(), Shell::Murex, '\x1b', 'J');
+        let mresult3 = wrap_seq_for_shell(test3.to_string(), Shell::Murex, 'O', 'O');
+        let mresult4 = wrap_seq_for_shell(test4.to_string(), Shell::Murex, '\x1b', 'm');
+        let mresult5 = wrap_seq_for_shell(test5.to_string(), Shell::Murex, '\x1b', 'm');
+
+        assert_eq!(&mresult0, "%{\x1b2m%}hellomynamekeyes%{\x1b2m%}");
+        assert_eq!(&mresult1, "%{\x1b]330;m%}lol%{\x1b]0m%}");
+        assert_eq!(&mresult2, "%{\x1bJ%}");
+        assert_eq!(&mresult3, "%{OH NO%}");
+        assert_eq!(&mresult4, "herpaderp");
+        assert_eq!(&mresult5, "");
+    }
+
+    #[test]
+    fn test_color_sequence_wrappers_cmd() {
+        let test0 = "\x1b2mhellomynamekeyes\x1b2m"; // BEGIN: \x1b     END: m
+        let test1 = "\u{1b}J"; // BEGIN: \x1b     END: J
+        let test2 = "herpaderp";
+
+        let cresult0 = wrap_seq_for_shell(test0.to_string(), Shell::Cmd, '\x1b', 'm');
+        let cresult1 = wrap_seq_for_shell(test1.to_string(), Shell::Cmd, '\x1b', 'J');
+        let cresult2 = wrap_seq_for_shell(test2.to_string(), Shell::Cmd, '\x1b', 'm');
+
+        // cmd does not need any wrapping around escape sequences
+        assert_eq!(&cresult0, test0);
+        assert_eq!(&cresult1, test1);
+        assert_eq!(&cresult2, test2);
+    }
+
+    #[test]
+    fn test_color_sequence_wrappers_fish() {
+        let test0 = "\x1b2mhellomynamekeyes\x1b2m"; // BEGIN: \x1b     END: m
+        let test1 = "\x1b]0;title\x07prompt"; // BEGIN: \x1b     END: \x07
+        let test2 = "\u{1b}J"; // BEGIN: \x1b     END: J
+        let test3 = "herpaderp";
+
+        let fresult0 = wrap_seq_for_shell(test0.to_string(), Shell::Fish, '\x1b', 'm');
+        let fresult1 = wrap_seq_for_shell(test1.to_string(), Shell::Fish, '\x1b', '\x07');
+        let fresult2 = wrap_seq_for_shell(test2.to_string(), Shell::Fish, '\x1b', 'J');
+        let fresult3 = wrap_seq_for_shell(test3.to_string(), Shell::Fish, '\x1b', 'm');
+
+        // fish measures both SGR and OSC sequences itself, so nothing is wrapped
+        assert_eq!(&fresult0, test0);
+        assert_eq!(&fresult1, test1);
+        assert_eq!(&fresult2, test2);
+        assert_eq!(&fresult3, test3);
+    }
 }
@@ -0,0 +1,81 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("app_env")
+        .env("RAILS_ENV", "production")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn no_env_set() -> io::Result<()> {
+    let output = common::render_module("app_env")
+        .use_config(toml::toml! {
+            [app_env]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn production_env() -> io::Result<()> {
+    let output = common::render_module("app_env")
+        .env("RAILS_ENV", "production")
+        .use_config(toml::toml! {
+            [app_env]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Red.bold().paint("⚑ production"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn development_env() -> io::Result<()> {
+    let output = common::render_module("app_env")
+        .env("RAILS_ENV", "development")
+        .use_config(toml::toml! {
+            [app_env]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Green.bold().paint("⚑ development"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn other_env_with_custom_variables() -> io::Result<()> {
+    let output = common::render_module("app_env")
+        .env("RAILS_ENV", "production")
+        .env("DEPLOY_TARGET", "staging")
+        .use_config(toml::toml! {
+            [app_env]
+            disabled = false
+            variables = ["DEPLOY_TARGET"]
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Yellow.bold().paint("⚑ staging"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -43,6 +43,21 @@ fn region_set_with_alias() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn region_set_with_short_region() -> io::Result<()> {
+    let output = common::render_module("aws")
+        .env("AWS_REGION", "ap-southeast-2")
+        .use_config(toml::toml! {
+            [aws]
+            short_region = true
+        })
+        .output()?;
+    let expected = format!("on {} ", Color::Yellow.bold().paint("☁️  apse2"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn default_region_set() -> io::Result<()> {
     let output = common::render_module("aws")
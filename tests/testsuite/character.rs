@@ -111,6 +111,40 @@ fn char_module_zsh_keymap() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn char_module_helix_keymap() -> io::Result<()> {
+    let expected_normal = "❮";
+    let expected_select = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT";
+    let expected_insert = "❯";
+
+    // helix keymap is normal
+    let output = common::render_module("character")
+        .arg("--keymap=normal")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_normal));
+
+    // specified visual character for helix select mode
+    let output = common::render_module("character")
+        .use_config(toml::toml! {
+            [character]
+            visual_symbol = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT"
+        })
+        .arg("--keymap=select")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_select));
+
+    // helix keymap is insert
+    let output = common::render_module("character")
+        .arg("--keymap=insert")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_insert));
+
+    Ok(())
+}
+
 #[test]
 fn char_module_fish_keymap() -> io::Result<()> {
     let expected_vicmd = "❮";
@@ -138,13 +172,74 @@ fn char_module_fish_keymap() -> io::Result<()> {
     let actual = String::from_utf8(output.stdout).unwrap();
     assert!(actual.contains(&expected_specified));
 
-    // fish keymap is other
+    // specified visual character for fish visual mode
     let output = common::render_module("character")
+        .use_config(toml::toml! {
+            [character]
+            visual_symbol = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT"
+        })
         .env("STARSHIP_SHELL", "fish")
         .arg("--keymap=visual")
         .output()?;
     let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_specified));
+
+    // specified replace character for fish replace_one mode
+    let output = common::render_module("character")
+        .use_config(toml::toml! {
+            [character]
+            replace_symbol = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT"
+        })
+        .env("STARSHIP_SHELL", "fish")
+        .arg("--keymap=replace_one")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_specified));
+
+    // fish keymap is insert
+    let output = common::render_module("character")
+        .env("STARSHIP_SHELL", "fish")
+        .arg("--keymap=insert")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
     assert!(actual.contains(&expected_other));
 
     Ok(())
 }
+
+#[test]
+fn char_module_blesh_keymap() -> io::Result<()> {
+    let expected_vicmd = "❮";
+    let expected_visual = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT";
+    let expected_insert = "❯";
+
+    // ble.sh keymap is vi normal mode
+    let output = common::render_module("character")
+        .env("STARSHIP_SHELL", "bash")
+        .arg("--keymap=vi_nmap")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_vicmd));
+
+    // specified visual character for ble.sh visual mode
+    let output = common::render_module("character")
+        .use_config(toml::toml! {
+            [character]
+            visual_symbol = "I HIGHLY DOUBT THIS WILL SHOW UP IN OTHER OUTPUT"
+        })
+        .env("STARSHIP_SHELL", "bash")
+        .arg("--keymap=vi_xmap")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_visual));
+
+    // ble.sh keymap is vi insert mode
+    let output = common::render_module("character")
+        .env("STARSHIP_SHELL", "bash")
+        .arg("--keymap=vi_imap")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains(&expected_insert));
+
+    Ok(())
+}
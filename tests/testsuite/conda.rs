@@ -48,3 +48,58 @@ fn truncate() -> io::Result<()> {
     assert_eq!(expected, actual);
     Ok(())
 }
+
+#[test]
+fn ascii_symbol_style() -> io::Result<()> {
+    let output = common::render_module("conda")
+        .env_clear()
+        .env("CONDA_DEFAULT_ENV", "astronauts")
+        .use_config(toml::toml! {
+            symbol_style = "ascii"
+            [conda]
+            ascii_symbol = "conda "
+        })
+        .output()?;
+
+    let expected = format!("via {} ", Color::Green.bold().paint("conda astronauts"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn mamba_env_set() -> io::Result<()> {
+    let output = common::render_module("conda")
+        .env_clear()
+        .env("MAMBA_EXE", "/usr/local/bin/micromamba")
+        .env("MAMBA_DEFAULT_ENV", "astronauts")
+        .env("CONDA_DEFAULT_ENV", "base")
+        .use_config("".parse().unwrap())
+        .output()?;
+
+    let expected = format!("via {} ", Color::Green.bold().paint("M astronauts"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn mamba_falls_back_to_conda_env() -> io::Result<()> {
+    let output = common::render_module("conda")
+        .env_clear()
+        .env("MAMBA_EXE", "/usr/local/bin/micromamba")
+        .env("CONDA_DEFAULT_ENV", "astronauts")
+        .use_config(toml::toml! {
+            [conda]
+            mamba_symbol = "🐍 "
+        })
+        .output()?;
+
+    let expected = format!("via {} ", Color::Green.bold().paint("🐍 astronauts"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -39,3 +39,147 @@ fn add_newline_configuration() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn minimal_theme_configuration() -> io::Result<()> {
+    let default_output = common::render_prompt().output()?;
+    let default_prompt = String::from_utf8(default_output.stdout).unwrap();
+
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            theme = "minimal"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    // The minimal theme renders a single line with no leading newline
+    assert_eq!(actual.trim_start(), actual);
+    assert!(!actual.contains('\n'));
+    assert!(actual.len() < default_prompt.len());
+
+    Ok(())
+}
+
+#[test]
+fn full_theme_configuration() -> io::Result<()> {
+    let default_output = common::render_prompt().output()?;
+    let expected = String::from_utf8(default_output.stdout).unwrap();
+
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            theme = "full"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+fn no_color_env_var() -> io::Result<()> {
+    let output = common::render_prompt().env("NO_COLOR", "1").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(!actual.contains("\x1b["));
+    assert!(actual.ends_with("❯ "));
+
+    let output = common::render_module("character")
+        .env("NO_COLOR", "1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("❯ ", actual);
+
+    Ok(())
+}
+
+#[test]
+fn dumb_terminal() -> io::Result<()> {
+    let output = common::render_module("character")
+        .env("TERM", "dumb")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("❯ ", actual);
+
+    Ok(())
+}
+
+#[test]
+fn disable_env_var() -> io::Result<()> {
+    let output = common::render_prompt()
+        .env("STARSHIP_DISABLE", "true")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Green.bold().paint("❯"));
+    assert_eq!(expected, actual);
+
+    let output = common::render_prompt()
+        .env("STARSHIP_DISABLE", "0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_ne!(expected, actual);
+    assert!(actual.starts_with('\n'));
+
+    Ok(())
+}
+
+#[test]
+fn single_render_thread_configuration() -> io::Result<()> {
+    let config = toml::toml! {
+        add_newline = false
+        render_threads = 1
+        prompt_order = ["custom.first", "custom.second", "custom.third"]
+
+        [custom.first]
+        command = "echo first"
+        when = "true"
+
+        [custom.second]
+        command = "echo second"
+        when = "true"
+
+        [custom.third]
+        command = "echo third"
+        when = "true"
+    };
+
+    let output = common::render_prompt()
+        .use_config(config.clone())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let first = actual.find("first").unwrap();
+    let second = actual.find("second").unwrap();
+    let third = actual.find("third").unwrap();
+    assert!(first < second && second < third);
+
+    let output = common::render_prompt().use_config(config).output()?;
+    assert_eq!(actual, String::from_utf8(output.stdout).unwrap());
+
+    Ok(())
+}
+
+#[test]
+fn inline_config_toml() -> io::Result<()> {
+    let output = common::render_module("jobs")
+        .arg("--jobs=1")
+        .arg("--config-toml=[jobs]\nsymbol = \"+\"")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Blue.bold().paint("+"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn malformed_inline_config_toml() -> io::Result<()> {
+    let output = common::render_module("jobs")
+        .arg("--jobs=1")
+        .arg("--config-toml=[jobs")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Blue.bold().paint("✦"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn container_env_var() -> io::Result<()> {
+    let output = common::render_module("container")
+        .env("container", "podman")
+        .use_config(toml::toml! {
+            [container]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("in {} ", Color::Red.bold().dimmed().paint("⬢ [podman]"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("container")
+        .env("container", "podman")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use ansi_term::Color;
+use std::fs;
+use std::io;
+
+use crate::common;
+
+#[test]
+fn devbox_shell_active() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let output = common::render_module("devbox")
+        .arg("--path")
+        .arg(dir.path())
+        .env("DEVBOX_SHELL_ENABLED", "1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Green.bold().paint("📦 "));
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn devbox_shell_active_with_packages() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::write(
+        dir.path().join("devbox.json"),
+        r#"{"packages": ["go@1.21"]}"#,
+    )?;
+
+    let output = common::render_module("devbox")
+        .arg("--path")
+        .arg(dir.path())
+        .env("DEVBOX_SHELL_ENABLED", "1")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Green.bold().paint("📦 1 package"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
@@ -291,6 +291,32 @@ fn directory_in_git_repo() -> io::Result<()> {
     tmp_dir.close()
 }
 
+#[test]
+fn directory_in_jj_workspace() -> io::Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let repo_dir = tmp_dir.path().join("rocket-controls");
+    let dir = repo_dir.join("src/meters");
+    fs::create_dir_all(&dir)?;
+    fs::create_dir(repo_dir.join(".jj"))?;
+
+    let output = common::render_module("directory")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 8
+        })
+        .arg("--path")
+        .arg(dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "in {} ",
+        Color::Cyan.bold().paint("rocket-controls/src/meters")
+    );
+    assert_eq!(expected, actual);
+    tmp_dir.close()
+}
+
 #[test]
 #[ignore]
 fn truncated_directory_in_git_repo() -> io::Result<()> {
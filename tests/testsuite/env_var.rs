@@ -36,6 +36,40 @@ fn defined_variable() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn hex_encoded_variable() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var]
+            variable = "TEST_VAR"
+            decode_hex = true
+        })
+        .env("TEST_VAR", "617374726f6e61757473")
+        .output()?;
+    let expected = format!("with {} ", style().paint(TEST_VAR_VALUE));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn malformed_hex_encoded_variable() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var]
+            variable = "TEST_VAR"
+            decode_hex = true
+        })
+        .env("TEST_VAR", TEST_VAR_VALUE)
+        .output()?;
+    let expected = "";
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn undefined_variable() -> io::Result<()> {
     let output = common::render_module("env_var")
@@ -122,6 +122,73 @@ fn test_works_with_unborn_master() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn test_branch_type_symbols() -> io::Result<()> {
+    test_branch_type_symbol("feature/x", "✨ ")?;
+    test_branch_type_symbol("hotfix/y", "🚑 ")?;
+    test_branch_type_symbol("plain", "\u{e0a0} ")
+}
+
+#[test]
+fn test_dirty_symbol() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let render = || {
+        common::render_module("git_branch")
+            .use_config(toml::toml! {
+                [git_branch]
+                    dirty = "*"
+            })
+            .arg("--path")
+            .arg(&repo_dir)
+            .output()
+    };
+
+    let actual = String::from_utf8(render()?.stdout).unwrap();
+    let expected = format!("on {} ", Color::Purple.bold().paint("\u{e0a0} master"));
+    assert_eq!(expected, actual);
+
+    std::fs::write(repo_dir.join("readme.md"), "# goodbye\n")?;
+
+    let actual = String::from_utf8(render()?.stdout).unwrap();
+    let expected = format!("on {} ", Color::Purple.bold().paint("\u{e0a0} master*"));
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+fn test_branch_type_symbol(branch_name: &str, expected_symbol: &str) -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(&["checkout", "-b", branch_name])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+
+    let output = common::render_module("git_branch")
+        .use_config(
+            toml::from_str(
+                r#"
+                    [git_branch]
+                        branch_type_symbols = { "feature/" = "✨ ", "hotfix/" = "🚑 " }
+                "#,
+            )
+            .unwrap(),
+        )
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "on {} ",
+        Color::Purple
+            .bold()
+            .paint(format!("{}{}", expected_symbol, branch_name)),
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 fn test_truncate_length(
     branch_name: &str,
     truncate_length: i64,
@@ -83,6 +83,43 @@ fn test_render_commit_hash_len_override() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+fn test_render_commit_hash_with_tag() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(&["tag", "v1.0.0"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+
+    let mut git_output = Command::new("git")
+        .args(&["rev-parse", "HEAD"])
+        .current_dir(repo_dir.as_path())
+        .output()?
+        .stdout;
+    git_output.truncate(7);
+    let expected_hash = str::from_utf8(&git_output).unwrap();
+
+    let output = common::render_module("git_commit")
+        .use_config(toml::toml! {
+            [git_commit]
+                only_detached = false
+                tag_disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Green
+        .bold()
+        .paint(format!("({} 🏷 v1.0.0) ", expected_hash))
+        .to_string();
+
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 fn test_render_commit_hash_only_detached_on_branch() -> io::Result<()> {
     let repo_dir = common::create_fixture_repo()?;
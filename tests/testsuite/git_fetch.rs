@@ -0,0 +1,108 @@
+use ansi_term::Color;
+use remove_dir_all::remove_dir_all;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::common::{self, TestCommand};
+
+fn set_fetch_age(repo_dir: &Path, age: Duration) -> io::Result<()> {
+    let fetch_head = File::create(repo_dir.join(".git").join("FETCH_HEAD"))?;
+    fetch_head.set_modified(SystemTime::now() - age)?;
+    fetch_head.sync_all()
+}
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    set_fetch_age(&repo_dir, Duration::from_secs(2 * 60 * 60))?;
+
+    let output = common::render_module("git_fetch")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn show_nothing_if_never_fetched() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_fetch")
+        .use_config(toml::toml! {
+            [git_fetch]
+                disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn recent_fetch() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    set_fetch_age(&repo_dir, Duration::from_secs(2 * 60 * 60))?;
+
+    let output = common::render_module("git_fetch")
+        .use_config(toml::toml! {
+            [git_fetch]
+                disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with(&Color::Green.bold().prefix().to_string()));
+    assert!(actual.contains("fetched 2h"));
+    assert!(actual.contains(" ago "));
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn stale_fetch() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    set_fetch_age(&repo_dir, Duration::from_secs(3 * 24 * 60 * 60))?;
+
+    let output = common::render_module("git_fetch")
+        .use_config(toml::toml! {
+            [git_fetch]
+                disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with(&Color::Red.bold().prefix().to_string()));
+    assert!(actual.contains("fetched 3d"));
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn hidden_below_min_age() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    set_fetch_age(&repo_dir, Duration::from_secs(2 * 60 * 60))?;
+
+    let output = common::render_module("git_fetch")
+        .use_config(toml::toml! {
+            [git_fetch]
+                disabled = false
+                min_age = 21600
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
@@ -0,0 +1,111 @@
+use ansi_term::Color;
+use remove_dir_all::remove_dir_all;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    append_line(&repo_dir.join("readme.md"), "rocket science")?;
+
+    let output = common::render_module("git_metrics")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn clean_repo() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn shows_added_lines() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    append_line(&repo_dir.join("readme.md"), "rocket science")?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Green.bold().paint("+1"));
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn shows_zero_counts() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    append_line(&repo_dir.join("readme.md"), "rocket science")?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+            only_nonzero_diffs = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "{} {} ",
+        Color::Green.bold().paint("+1"),
+        Color::Red.bold().paint("-0")
+    );
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+fn git_executable_fallback() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    append_line(&repo_dir.join("readme.md"), "rocket science")?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+            use_git_executable = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Green.bold().paint("+1"));
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
+fn append_line(path: &std::path::Path, line: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+    file.sync_all()
+}
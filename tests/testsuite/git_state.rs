@@ -53,6 +53,23 @@ fn shows_merging() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn shows_merge_source() -> io::Result<()> {
+    let repo_dir = create_repo_with_conflict()?;
+    let path = path_str(&repo_dir)?;
+
+    run_git_cmd(&["merge", "other-branch"], Some(path), false)?;
+
+    let output = common::render_module("git_state")
+        .current_dir(path)
+        .output()?;
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.contains("MERGING other-branch"));
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn shows_cherry_picking() -> io::Result<()> {
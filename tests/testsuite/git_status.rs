@@ -53,6 +53,39 @@ fn shows_behind() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_ahead_behind_compare_remote() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    ahead(&repo_dir)?;
+    upstream_remote_ahead(&repo_dir)?;
+
+    // Against the tracked origin/master the branch is ahead
+    let output = common::render_module("git_status")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "⇡")).to_string();
+    assert_eq!(expected, actual);
+
+    // Against upstream/master the same branch is behind
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            compare_remote = "upstream"
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "⇣")).to_string();
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_behind_with_count() -> io::Result<()> {
@@ -165,6 +198,25 @@ fn shows_diverged_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_upstream_gone() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    track_gone_upstream(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "⊘")).to_string();
+
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_conflicted() -> io::Result<()> {
@@ -329,6 +381,76 @@ fn shows_stashed_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_latest_stash_message_and_age() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    barrier();
+
+    File::create(repo_dir.join("readme.md"))?.sync_all()?;
+    barrier();
+
+    Command::new("git")
+        .args(&["stash", "push", "-m", "half-finished rocket"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            show_stash_message = true
+            show_stash_age = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let message = " half-finished rocket ";
+    let age = actual
+        .split(message)
+        .nth(1)
+        .and_then(|rest| rest.split(']').next())
+        .unwrap_or_default();
+
+    assert!(actual.contains(&format!("[${}", message)));
+    assert!(!age.is_empty());
+
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+#[ignore]
+fn shows_styled_stash_message() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    barrier();
+
+    File::create(repo_dir.join("readme.md"))?.sync_all()?;
+    barrier();
+
+    Command::new("git")
+        .args(&["stash", "push", "-m", "half-finished rocket"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            show_stash_message = true
+            stash_message = { value = " 📝", style = "green" }
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} 📝half-finished rocket", Color::Green.prefix());
+
+    assert!(actual.contains(&expected), "{:?}", actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_modified() -> io::Result<()> {
@@ -370,6 +492,67 @@ fn shows_modified_with_count() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_capped_count_with_max_changed_files() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    for i in 0..5 {
+        File::create(repo_dir.join(format!("untracked_{}", i)))?;
+    }
+    barrier();
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            untracked_count.enabled = true
+            max_changed_files = 3
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "?3+")).to_string();
+
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
+#[test]
+#[ignore]
+fn only_marks_truncated_counts_with_max_changed_files() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_modified(&repo_dir)?;
+    for i in 0..5 {
+        File::create(repo_dir.join(format!("untracked_{}", i)))?;
+    }
+    barrier();
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            modified_count.enabled = true
+            untracked_count.enabled = true
+            max_changed_files = 3
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    // Changes are counted in path order, so the modified readme is counted in
+    // full and only the untracked files are cut off
+    let expected = Color::Red
+        .bold()
+        .paint(format!("[{}] ", "!1?2+"))
+        .to_string();
+
+    assert_eq!(expected, actual);
+
+    remove_dir_all(repo_dir)
+}
+
 #[test]
 #[ignore]
 fn shows_staged_file() -> io::Result<()> {
@@ -546,6 +729,31 @@ fn suffix() -> io::Result<()> {
     remove_dir_all(repo_dir)
 }
 
+#[test]
+#[ignore]
+fn shows_todo_count() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    fs::write(
+        repo_dir.join("readme.md"),
+        "TODO: write a readme\nFIXME: and fix it\n",
+    )?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            show_todo_count = true
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "!✎2")).to_string();
+
+    assert_eq!(expected, actual);
+    remove_dir_all(repo_dir)
+}
+
 fn ahead(repo_dir: &PathBuf) -> io::Result<()> {
     File::create(repo_dir.join("readme.md"))?.sync_all()?;
 
@@ -586,6 +794,41 @@ fn diverge(repo_dir: &PathBuf) -> io::Result<()> {
     Ok(())
 }
 
+/// Point `upstream/master` one commit past the current `HEAD`
+fn upstream_remote_ahead(repo_dir: &PathBuf) -> io::Result<()> {
+    fs::write(repo_dir.join("readme.md"), "# upstream")?;
+
+    Command::new("git")
+        .args(&["commit", "-am", "Upstream change"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    Command::new("git")
+        .args(&["update-ref", "refs/remotes/upstream/master", "HEAD"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    Command::new("git")
+        .args(&["reset", "--hard", "HEAD^"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    Ok(())
+}
+
+fn track_gone_upstream(repo_dir: &PathBuf) -> io::Result<()> {
+    Command::new("git")
+        .args(&["config", "branch.master.merge", "refs/heads/deleted-branch"])
+        .current_dir(repo_dir.as_path())
+        .output()?;
+    barrier();
+
+    Ok(())
+}
+
 fn create_conflict(repo_dir: &PathBuf) -> io::Result<()> {
     Command::new("git")
         .args(&["reset", "--hard", "HEAD^"])
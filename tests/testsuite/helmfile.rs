@@ -0,0 +1,39 @@
+use std::fs;
+use std::io;
+
+use crate::common;
+
+#[test]
+fn folder_without_helmfile() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let output = common::render_module("helmfile")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+#[ignore]
+fn folder_with_helmfile_environment() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::write(
+        dir.path().join("helmfile.yaml"),
+        "environments:\n  default: {}\n  staging: {}\n",
+    )?;
+
+    let output = common::render_module("helmfile")
+        .arg("--path")
+        .arg(dir.path())
+        .env("HELMFILE_ENVIRONMENT", "staging")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.contains("(staging)"));
+    dir.close()
+}
@@ -59,6 +59,48 @@ fn ssh() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ssh_client() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = true
+            trim_at = ""
+        })
+        .env("SSH_CLIENT", "something")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", style().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn ssh_tty() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = true
+            trim_at = ""
+        })
+        .env("SSH_TTY", "/dev/pts/0")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", style().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn prefix() -> io::Result<()> {
     let hostname = match get_hostname() {
@@ -142,6 +184,48 @@ fn trim_at() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn baseline_matches() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = false
+            trim_at = ""
+            baseline = hostname
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("", actual);
+    Ok(())
+}
+
+#[test]
+fn baseline_differs() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let baseline = format!("not-{}", hostname);
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = false
+            trim_at = ""
+            baseline = baseline
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", style().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 fn get_hostname() -> Option<String> {
     match gethostname::gethostname().into_string() {
         Ok(hostname) => Some(hostname),
@@ -0,0 +1,88 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("locale")
+        .env_clear()
+        .env("LANG", "de_DE.UTF-8")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn shows_short_locale() -> io::Result<()> {
+    let output = common::render_module("locale")
+        .env_clear()
+        .env("LANG", "de_DE.UTF-8")
+        .use_config(toml::toml! {
+            [locale]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::White.dimmed().paint("🌐 de_DE"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn shows_full_locale() -> io::Result<()> {
+    let output = common::render_module("locale")
+        .env_clear()
+        .env("LANG", "de_DE.UTF-8")
+        .use_config(toml::toml! {
+            [locale]
+            disabled = false
+            show_full = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::White.dimmed().paint("🌐 de_DE.UTF-8"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn lc_all_overrides_lang() -> io::Result<()> {
+    let output = common::render_module("locale")
+        .env_clear()
+        .env("LANG", "de_DE.UTF-8")
+        .env("LC_ALL", "fr_FR.UTF-8")
+        .use_config(toml::toml! {
+            [locale]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::White.dimmed().paint("🌐 fr_FR"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn hides_default_locale() -> io::Result<()> {
+    let output = common::render_module("locale")
+        .env_clear()
+        .env("LANG", "de_DE.UTF-8")
+        .use_config(toml::toml! {
+            [locale]
+            disabled = false
+            default_locale = "de_DE"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -1,24 +1,35 @@
+mod app_env;
 mod aws;
 mod character;
 mod cmd_duration;
 mod common;
 mod conda;
 mod configuration;
+mod container;
+mod devbox;
 mod directory;
 mod dotnet;
 mod env_var;
 mod git_branch;
 mod git_commit;
+mod git_fetch;
+mod git_metrics;
 mod git_state;
 mod git_status;
+mod helmfile;
 mod hg_branch;
 mod hostname;
 mod jobs;
 mod line_break;
+mod locale;
 mod modules;
 mod nix_shell;
+mod pid;
 mod python;
 mod singularity;
+mod sops;
+mod systemd;
 mod terraform;
 mod time;
 mod username;
+mod zellij;
@@ -1,7 +1,7 @@
 use ansi_term::Color;
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 #[test]
 fn no_env_variables() -> io::Result<()> {
@@ -56,3 +56,18 @@ fn lorri_shell() -> io::Result<()> {
     assert_eq!(expected, actual);
     Ok(())
 }
+
+#[test]
+fn ascii_symbol_style() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env("IN_NIX_SHELL", "pure")
+        .use_config(toml::toml! {
+            symbol_style = "ascii"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Blue.bold().paint("nix pure"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn pid_not_passed() -> io::Result<()> {
+    let output = common::render_module("pid")
+        .use_config(toml::toml! {
+            [pid]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn pid_passed() -> io::Result<()> {
+    let output = common::render_module("pid")
+        .arg("--pid=4242")
+        .use_config(toml::toml! {
+            [pid]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("{} ", Color::Purple.bold().paint("PID 4242"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("pid").arg("--pid=4242").output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
@@ -173,6 +173,27 @@ fn with_virtual_env() -> io::Result<()> {
     dir.close()
 }
 
+#[test]
+#[ignore]
+fn with_virtual_env_prompt() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join("main.py"))?.sync_all()?;
+    let output = common::render_module("python")
+        .env("VIRTUAL_ENV", "/foo/bar/.venv")
+        .env("VIRTUAL_ENV_PROMPT", "(my-project) ")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "via {} ",
+        Color::Yellow.bold().paint("🐍 v3.8.2 (my-project)")
+    );
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
 #[test]
 #[ignore]
 fn with_active_venv() -> io::Result<()> {
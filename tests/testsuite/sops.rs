@@ -0,0 +1,57 @@
+use ansi_term::Color;
+use std::fs::File;
+use std::io;
+
+use crate::common;
+
+#[test]
+fn folder_without_sops_config() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let output = common::render_module("sops")
+        .arg("--path")
+        .arg(dir.path())
+        .env("SOPS_AGE_KEY", "AGE-SECRET-KEY-1EXAMPLE")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn sops_config_with_key() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join(".sops.yaml"))?.sync_all()?;
+
+    let output = common::render_module("sops")
+        .arg("--path")
+        .arg(dir.path())
+        .env(
+            "SOPS_AGE_KEY_FILE",
+            "/home/astronaut/.config/sops/age/keys.txt",
+        )
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Green.bold().paint("🔐 key"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
+#[test]
+fn sops_config_without_key() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join(".sops.yaml"))?.sync_all()?;
+
+    let output = common::render_module("sops")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Red.bold().paint("🔐 no key"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
@@ -0,0 +1,49 @@
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn normal_session() -> io::Result<()> {
+    let output = common::render_module("systemd")
+        .env_clear()
+        .use_config(toml::toml! {
+            [systemd]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn invocation_id_set() -> io::Result<()> {
+    let output = common::render_module("systemd")
+        .env_clear()
+        .env("INVOCATION_ID", "e5b3b1a8c5f24b0e9d6a1d2f3c4b5a69")
+        .use_config(toml::toml! {
+            [systemd]
+            disabled = false
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert!(actual.starts_with("in "));
+    assert!(actual.contains("⚙ "));
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> io::Result<()> {
+    let output = common::render_module("systemd")
+        .env_clear()
+        .env("INVOCATION_ID", "e5b3b1a8c5f24b0e9d6a1d2f3c4b5a69")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
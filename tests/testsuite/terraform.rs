@@ -37,6 +37,23 @@ fn folder_with_tf_file() -> io::Result<()> {
     dir.close()
 }
 
+#[test]
+#[ignore]
+fn folder_with_terragrunt_file() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    File::create(dir.path().join("terragrunt.hcl"))?;
+
+    let output = common::render_module("terraform")
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Fixed(105).bold().paint("🌱 default"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
 #[test]
 #[ignore]
 fn folder_with_workspace_override() -> io::Result<()> {
@@ -99,6 +116,25 @@ fn folder_with_dotterraform_no_environment() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn folder_with_nested_dotterraform() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("stacks/network/.terraform"))?;
+
+    let output = common::render_module("terraform")
+        .use_config(toml::toml! {
+            scan_depth = 3
+        })
+        .arg("--path")
+        .arg(dir.path())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Fixed(105).bold().paint("💠 default"));
+    assert_eq!(expected, actual);
+    dir.close()
+}
+
 #[test]
 #[ignore]
 fn folder_with_dotterraform_with_environment() -> io::Result<()> {
@@ -0,0 +1,49 @@
+use ansi_term::Color;
+use std::io;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+fn no_env_set() -> io::Result<()> {
+    let output = common::render_module("zellij")
+        .env_clear()
+        .env("PATH", env!("PATH"))
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = "";
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn session_name_set() -> io::Result<()> {
+    let output = common::render_module("zellij")
+        .env_clear()
+        .env("ZELLIJ", "0")
+        .env("ZELLIJ_SESSION_NAME", "rocket-launch")
+        .output()?;
+
+    let expected = format!("in {} ", Color::Cyan.bold().paint("⧉ rocket-launch"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn ascii_symbol_style() -> io::Result<()> {
+    let output = common::render_module("zellij")
+        .env_clear()
+        .env("ZELLIJ_SESSION_NAME", "rocket-launch")
+        .use_config(toml::toml! {
+            symbol_style = "ascii"
+        })
+        .output()?;
+
+    let expected = format!("in {} ", Color::Cyan.bold().paint("zellij rocket-launch"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(expected, actual);
+    Ok(())
+}